@@ -0,0 +1,728 @@
+// Derive macros for haskell_bits, i.e. the equivalent of GHC's `DeriveFunctor`,
+// `DeriveFoldable` and `DeriveTraversable`.
+//
+// `#[derive(TypeCon)]` on `Tree<T>` generates `TreeTypeCon` (using `type_con!`),
+// and the other derives implement the traits for that type constructor:
+//
+// * `Functor` gives `LinearFunctor` and `Functor`
+// * `Foldable` gives `LinearFoldable` and `Foldable`
+// * `Traversable` gives `LinearTraversable`
+//
+// The type constructor abstracts over the last type parameter, unless another one is picked
+// with `#[haskell_bits(param = T)]`. The other type parameters are fixed, and can't have bounds.
+//
+// The instances are generated structurally. Fields of type `T` have the function applied,
+// fields which don't mention `T` are left alone (or cloned, for `Functor::fmap`),
+// and `Box<...>`, tuples and the type itself are recursed into directly.
+// Any other type mentioning `T`, like `Vec<T>` or `Option<Tree<T>>`, is handled by calling
+// `lmap`, `fmap`, `lfoldr`, `foldr` or `traverse` on it, so it needs type constructor instances
+// itself.
+
+extern crate proc_macro;
+
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, GenericParam, Member, Result, Type,
+    Visibility,
+};
+
+#[proc_macro_derive(TypeCon, attributes(haskell_bits))]
+pub fn derive_type_con(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(parse_macro_input!(input as DeriveInput), type_con)
+}
+
+#[proc_macro_derive(Functor, attributes(haskell_bits))]
+pub fn derive_functor(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(parse_macro_input!(input as DeriveInput), functor)
+}
+
+#[proc_macro_derive(Foldable, attributes(haskell_bits))]
+pub fn derive_foldable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(parse_macro_input!(input as DeriveInput), foldable)
+}
+
+#[proc_macro_derive(Traversable, attributes(haskell_bits))]
+pub fn derive_traversable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(parse_macro_input!(input as DeriveInput), traversable)
+}
+
+fn expand(input: DeriveInput, f: fn(&Info) -> Result<TokenStream>) -> proc_macro::TokenStream {
+    Info::new(&input)
+        .and_then(|info| f(&info))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct Info<'a> {
+    vis: &'a Visibility,
+    name: &'a Ident,
+    con: Ident,
+    // The parameter the type constructor abstracts over
+    param: Ident,
+    // All the type parameters, in order
+    params: Vec<Ident>,
+    // All the type parameters except `param`
+    fixed: Vec<Ident>,
+    constructors: Vec<Constructor>,
+}
+
+// A struct, or one variant of an enum
+struct Constructor {
+    path: TokenStream,
+    style: Style,
+    fields: Vec<Field>,
+}
+
+enum Style {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+struct Field {
+    member: Member,
+    binding: Ident,
+    shape: Shape,
+}
+
+// How a field's type relates to the parameter `T`
+enum Shape {
+    // Doesn't mention `T` at all
+    Fixed,
+    // Is exactly `T`
+    Param,
+    // Is the type being derived for, applied to `T`
+    Recurse,
+    Boxed(Box<Shape>),
+    Tuple(Vec<Shape>),
+    // Some other type with a type constructor, where `Type` is the argument mentioning `T`
+    Container(Box<Type>, Box<Shape>),
+}
+
+impl<'a> Info<'a> {
+    fn new(input: &'a DeriveInput) -> Result<Info<'a>> {
+        if let Some(where_clause) = &input.generics.where_clause {
+            return Err(Error::new_spanned(
+                where_clause,
+                "haskell_bits derives don't support where clauses",
+            ));
+        }
+
+        let mut params = Vec::new();
+        for generic_param in &input.generics.params {
+            match generic_param {
+                GenericParam::Type(type_param)
+                    if type_param.bounds.is_empty() && type_param.default.is_none() =>
+                {
+                    params.push(type_param.ident.clone())
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        generic_param,
+                        "haskell_bits derives only support type parameters without bounds",
+                    ))
+                }
+            }
+        }
+
+        let mut param = None;
+        for attr in &input.attrs {
+            if attr.path().is_ident("haskell_bits") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("param") {
+                        param = Some(meta.value()?.parse::<Ident>()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `param = ...`"))
+                    }
+                })?;
+            }
+        }
+
+        let param = match param.or_else(|| params.last().cloned()) {
+            Some(param) if params.contains(&param) => param,
+            Some(param) => {
+                return Err(Error::new_spanned(
+                    param,
+                    "not a type parameter of this type",
+                ))
+            }
+            None => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "haskell_bits derives need a type parameter to abstract over",
+                ))
+            }
+        };
+
+        let fixed = params.iter().filter(|p| **p != param).cloned().collect();
+
+        let mut info = Info {
+            vis: &input.vis,
+            name: &input.ident,
+            con: format_ident!("{}TypeCon", input.ident),
+            param,
+            params,
+            fixed,
+            constructors: Vec::new(),
+        };
+
+        let name = &input.ident;
+        info.constructors = match &input.data {
+            Data::Struct(data) => vec![info.constructor(quote!(#name), &data.fields)?],
+            Data::Enum(data) => data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_name = &variant.ident;
+                    info.constructor(quote!(#name::#variant_name), &variant.fields)
+                })
+                .collect::<Result<_>>()?,
+            Data::Union(data) => {
+                return Err(Error::new_spanned(
+                    data.union_token,
+                    "haskell_bits derives don't support unions",
+                ))
+            }
+        };
+
+        Ok(info)
+    }
+
+    fn constructor(&self, path: TokenStream, fields: &Fields) -> Result<Constructor> {
+        let style = match fields {
+            Fields::Named(_) => Style::Named,
+            Fields::Unnamed(_) => Style::Unnamed,
+            Fields::Unit => Style::Unit,
+        };
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let (member, binding) = match &field.ident {
+                    Some(ident) => (
+                        Member::from(ident.clone()),
+                        format_ident!("__field_{}", ident),
+                    ),
+                    None => (Member::from(i), format_ident!("__field_{}", i)),
+                };
+                Ok(Field {
+                    member,
+                    binding,
+                    shape: self.shape(&field.ty)?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Constructor {
+            path,
+            style,
+            fields,
+        })
+    }
+
+    fn shape(&self, ty: &Type) -> Result<Shape> {
+        if !mentions(quote!(#ty), &self.param) {
+            return Ok(Shape::Fixed);
+        }
+        match ty {
+            Type::Paren(ty) => self.shape(&ty.elem),
+            Type::Group(ty) => self.shape(&ty.elem),
+            Type::Tuple(ty) => Ok(Shape::Tuple(
+                ty.elems
+                    .iter()
+                    .map(|elem| self.shape(elem))
+                    .collect::<Result<_>>()?,
+            )),
+            Type::Path(type_path) if type_path.qself.is_none() => {
+                if type_path.path.is_ident(&self.param) {
+                    return Ok(Shape::Param);
+                }
+                let segment = type_path.path.segments.last().unwrap();
+                let args: Vec<&Type> = match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) => args
+                        .args
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            syn::GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                if segment.ident == "Box" && args.len() == 1 {
+                    return Ok(Shape::Boxed(Box::new(self.shape(args[0])?)));
+                }
+                if segment.ident == *self.name
+                    && args.len() == self.params.len()
+                    && args
+                        .iter()
+                        .zip(&self.params)
+                        .all(|(arg, p)| is_ident(arg, p))
+                {
+                    return Ok(Shape::Recurse);
+                }
+                let mut mentioning = args
+                    .into_iter()
+                    .filter(|arg| mentions(quote!(#arg), &self.param));
+                match (mentioning.next(), mentioning.next()) {
+                    (Some(arg), None) => Ok(Shape::Container(
+                        Box::new(arg.clone()),
+                        Box::new(self.shape(arg)?),
+                    )),
+                    _ => Err(Error::new_spanned(
+                        ty,
+                        "haskell_bits derives need exactly one type argument here to mention the parameter",
+                    )),
+                }
+            }
+            _ => Err(Error::new_spanned(
+                ty,
+                "haskell_bits derives don't support this type of field",
+            )),
+        }
+    }
+
+    // The type, with `param` replaced by `arg`
+    fn applied_to(&self, arg: &TokenStream) -> TokenStream {
+        let name = self.name;
+        let args = self.params.iter().map(|p| {
+            if *p == self.param {
+                arg.clone()
+            } else {
+                quote!(#p)
+            }
+        });
+        quote!(#name<#(#args),*>)
+    }
+
+    fn con_type(&self) -> TokenStream {
+        let con = &self.con;
+        let fixed = &self.fixed;
+        if fixed.is_empty() {
+            quote!(#con)
+        } else {
+            quote!(#con<#(#fixed),*>)
+        }
+    }
+
+    // A match over all the constructors, binding each field to its `binding`
+    fn match_arms(&self, f: impl Fn(&Constructor) -> TokenStream) -> TokenStream {
+        let arms = self.constructors.iter().map(|constructor| {
+            let pattern = constructor.build(|field| {
+                let binding = &field.binding;
+                quote!(#binding)
+            });
+            let body = f(constructor);
+            quote!(#pattern => #body,)
+        });
+        quote!(match __x { #(#arms)* })
+    }
+}
+
+impl Constructor {
+    fn build(&self, f: impl Fn(&Field) -> TokenStream) -> TokenStream {
+        let path = &self.path;
+        let members = self.fields.iter().map(|field| &field.member);
+        let values = self.fields.iter().map(f);
+        match self.style {
+            Style::Named => quote!(#path { #(#members: #values),* }),
+            Style::Unnamed => quote!(#path(#(#values),*)),
+            Style::Unit => quote!(#path),
+        }
+    }
+}
+
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(i) => i == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+fn is_ident(ty: &Type, ident: &Ident) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident(ident),
+        _ => false,
+    }
+}
+
+fn substitute(tokens: TokenStream, from: &Ident, to: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(i) if i == *from => to.clone(),
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), substitute(group.stream(), from, to));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group).into()
+            }
+            other => other.into(),
+        })
+        .collect()
+}
+
+fn tuple_bindings(n: usize) -> Vec<Ident> {
+    (0..n).map(|i| format_ident!("__t{}", i)).collect()
+}
+
+fn type_con(info: &Info) -> Result<TokenStream> {
+    let vis = info.vis;
+    let con = &info.con;
+    let fixed = &info.fixed;
+    let param = &info.param;
+    let target = info.applied_to(&quote!(#param));
+    Ok(if fixed.is_empty() {
+        quote!(::haskell_bits::type_con!(#vis struct #con for<#param> #target);)
+    } else {
+        quote!(::haskell_bits::type_con!(#vis struct #con<#(#fixed),*> for<#param> #target);)
+    })
+}
+
+fn functor(info: &Info) -> Result<TokenStream> {
+    let con = info.con_type();
+    let fixed = &info.fixed;
+    let param = &info.param;
+    let t_in = quote!(__TIn);
+    let t_out = quote!(__TOut);
+    let in_type = info.applied_to(&t_in);
+    let out_type = info.applied_to(&t_out);
+
+    // `e` is a value of the field's type
+    fn lmap_field(param: &Ident, shape: &Shape, e: TokenStream) -> TokenStream {
+        match shape {
+            Shape::Fixed => e,
+            Shape::Param => quote!(__f(#e)),
+            Shape::Recurse => quote!(__go(__f, #e)),
+            Shape::Boxed(shape) => {
+                let inner = lmap_field(param, shape, quote!(*#e));
+                quote!(::std::boxed::Box::new(#inner))
+            }
+            Shape::Tuple(shapes) => {
+                let bindings = tuple_bindings(shapes.len());
+                let values = shapes
+                    .iter()
+                    .zip(&bindings)
+                    .map(|(shape, binding)| lmap_field(param, shape, quote!(#binding)));
+                quote!({ let (#(#bindings,)*) = #e; (#(#values,)*) })
+            }
+            Shape::Container(arg, shape) => {
+                let arg = substitute(quote!(#arg), param, &quote!(__TIn));
+                let inner = lmap_field(param, shape, quote!(__y));
+                quote!(::haskell_bits::lmap(|__y: #arg| #inner, #e))
+            }
+        }
+    }
+
+    // `e` is a reference to a value of the field's type
+    fn fmap_field(param: &Ident, shape: &Shape, e: TokenStream) -> TokenStream {
+        match shape {
+            Shape::Fixed => quote!(::std::clone::Clone::clone(#e)),
+            Shape::Param => quote!(__f(#e)),
+            Shape::Recurse => quote!(__go(__f, #e)),
+            Shape::Boxed(shape) => {
+                let inner = fmap_field(param, shape, quote!(&**#e));
+                quote!(::std::boxed::Box::new(#inner))
+            }
+            Shape::Tuple(shapes) => {
+                let bindings = tuple_bindings(shapes.len());
+                let values = shapes
+                    .iter()
+                    .zip(&bindings)
+                    .map(|(shape, binding)| fmap_field(param, shape, quote!(#binding)));
+                quote!({ let (#(#bindings,)*) = #e; (#(#values,)*) })
+            }
+            Shape::Container(arg, shape) => {
+                let arg = substitute(quote!(#arg), param, &quote!(__TIn));
+                let inner = fmap_field(param, shape, quote!(__y));
+                quote!(::haskell_bits::fmap(|__y: &#arg| #inner, #e))
+            }
+        }
+    }
+
+    let lmap_body = info.match_arms(|constructor| {
+        constructor.build(|field| {
+            let binding = &field.binding;
+            lmap_field(param, &field.shape, quote!(#binding))
+        })
+    });
+    let fmap_body = info.match_arms(|constructor| {
+        constructor.build(|field| {
+            let binding = &field.binding;
+            fmap_field(param, &field.shape, quote!(#binding))
+        })
+    });
+
+    Ok(quote! {
+        impl<#(#fixed),*> ::haskell_bits::LinearFunctor for #con {
+            fn lmap<__TIn, __TOut, __F>(
+                __f: __F,
+                __x: <Self as ::haskell_bits::WithTypeArg<__TIn>>::Type,
+            ) -> <Self as ::haskell_bits::WithTypeArg<__TOut>>::Type
+            where
+                __F: Fn(__TIn) -> __TOut,
+            {
+                fn __go<#(#fixed,)* __TIn, __TOut>(
+                    __f: &dyn Fn(__TIn) -> __TOut,
+                    __x: #in_type,
+                ) -> #out_type {
+                    #lmap_body
+                }
+                __go(&__f, __x)
+            }
+        }
+
+        impl<#(#fixed),*> ::haskell_bits::Functor for #con
+        where
+            #(#fixed: ::std::clone::Clone,)*
+        {
+            fn fmap<__TIn, __TOut, __F>(
+                __f: __F,
+                __x: &<Self as ::haskell_bits::WithTypeArg<__TIn>>::Type,
+            ) -> <Self as ::haskell_bits::WithTypeArg<__TOut>>::Type
+            where
+                __F: Fn(&__TIn) -> __TOut,
+            {
+                fn __go<#(#fixed: ::std::clone::Clone,)* __TIn, __TOut>(
+                    __f: &dyn Fn(&__TIn) -> __TOut,
+                    __x: &#in_type,
+                ) -> #out_type {
+                    #fmap_body
+                }
+                __go(&__f, __x)
+            }
+        }
+    })
+}
+
+fn foldable(info: &Info) -> Result<TokenStream> {
+    let con = info.con_type();
+    let fixed = &info.fixed;
+    let param = &info.param;
+    let in_type = info.applied_to(&quote!(__TIn));
+
+    // Folds the value `e` into `acc`. `by_ref` says whether `e` is a reference.
+    fn fold_field(
+        param: &Ident,
+        by_ref: bool,
+        shape: &Shape,
+        e: TokenStream,
+        acc: TokenStream,
+    ) -> TokenStream {
+        match shape {
+            Shape::Fixed => acc,
+            Shape::Param => quote!(__f(#e, #acc)),
+            Shape::Recurse => quote!(__go(__f, #e, #acc)),
+            Shape::Boxed(shape) => {
+                let e = if by_ref { quote!(&**#e) } else { quote!(*#e) };
+                fold_field(param, by_ref, shape, e, acc)
+            }
+            Shape::Tuple(shapes) => {
+                let bindings = tuple_bindings(shapes.len());
+                let steps = shapes.iter().zip(&bindings).rev().map(|(shape, binding)| {
+                    let step = fold_field(param, by_ref, shape, quote!(#binding), quote!(__acc));
+                    quote!(let __acc = #step;)
+                });
+                quote!({ let (#(#bindings,)*) = #e; let __acc = #acc; #(#steps)* __acc })
+            }
+            Shape::Container(arg, shape) => {
+                let arg = substitute(quote!(#arg), param, &quote!(__TIn));
+                let inner = fold_field(param, by_ref, shape, quote!(__y), quote!(__acc));
+                if by_ref {
+                    quote!(::haskell_bits::foldr(|__y: &#arg, __acc| #inner, #acc, #e))
+                } else {
+                    quote!(::haskell_bits::lfoldr(|__y: #arg, __acc| #inner, #acc, #e))
+                }
+            }
+        }
+    }
+
+    let body = |by_ref: bool| {
+        info.match_arms(|constructor| {
+            let steps = constructor.fields.iter().rev().map(|field| {
+                let binding = &field.binding;
+                let step = fold_field(param, by_ref, &field.shape, quote!(#binding), quote!(__acc));
+                quote!(let __acc = #step;)
+            });
+            quote!({ #(#steps)* __acc })
+        })
+    };
+    let lfoldr_body = body(false);
+    let foldr_body = body(true);
+
+    Ok(quote! {
+        impl<#(#fixed),*> ::haskell_bits::LinearFoldable for #con {
+            fn lfoldr<__F, __TIn, __TOut>(
+                __f: __F,
+                __init: __TOut,
+                __x: <Self as ::haskell_bits::WithTypeArg<__TIn>>::Type,
+            ) -> __TOut
+            where
+                __F: Fn(__TIn, __TOut) -> __TOut,
+            {
+                #[allow(unused_variables)]
+                fn __go<#(#fixed,)* __TIn, __TOut>(
+                    __f: &dyn Fn(__TIn, __TOut) -> __TOut,
+                    __x: #in_type,
+                    __acc: __TOut,
+                ) -> __TOut {
+                    #lfoldr_body
+                }
+                __go(&__f, __x, __init)
+            }
+        }
+
+        impl<#(#fixed),*> ::haskell_bits::Foldable for #con {
+            fn foldr<__F, __TIn, __TOut>(
+                __f: __F,
+                __init: __TOut,
+                __x: &<Self as ::haskell_bits::WithTypeArg<__TIn>>::Type,
+            ) -> __TOut
+            where
+                __F: Fn(&__TIn, __TOut) -> __TOut,
+            {
+                #[allow(unused_variables)]
+                fn __go<#(#fixed,)* __TIn, __TOut>(
+                    __f: &dyn Fn(&__TIn, __TOut) -> __TOut,
+                    __x: &#in_type,
+                    __acc: __TOut,
+                ) -> __TOut {
+                    #foldr_body
+                }
+                __go(&__f, __x, __init)
+            }
+        }
+    })
+}
+
+fn traversable(info: &Info) -> Result<TokenStream> {
+    let con = info.con_type();
+    let fixed = &info.fixed;
+    let param = &info.param;
+    let in_type = info.applied_to(&quote!(
+        <__TA as ::haskell_bits::gat::HigherKinded>::Of<__T>
+    ));
+    let out_type = info.applied_to(&quote!(__T));
+
+    // `e` is a value of the field's type, with effects in place of `T`. This gives an effect
+    // producing the field's type with `T`, combining the effects in the order `lmap` visits them.
+    fn sequence_field(
+        param: &Ident,
+        fixed: &[Ident],
+        shape: &Shape,
+        e: TokenStream,
+    ) -> TokenStream {
+        match shape {
+            Shape::Fixed => quote!(<__TA as ::haskell_bits::Lift>::lift(#e)),
+            Shape::Param => e,
+            Shape::Recurse => quote!(__go::<#(#fixed,)* __TA, __T>(#e)),
+            Shape::Boxed(shape) => {
+                let inner = sequence_field(param, fixed, shape, quote!(*#e));
+                quote!(<__TA as ::haskell_bits::LinearApplicative>::lap(
+                    <__TA as ::haskell_bits::Lift>::lift(::std::boxed::Box::new),
+                    #inner,
+                ))
+            }
+            Shape::Tuple(shapes) => {
+                let bindings = tuple_bindings(shapes.len());
+                let effects = shapes
+                    .iter()
+                    .zip(&bindings)
+                    .filter(|(shape, _)| !matches!(shape, Shape::Fixed))
+                    .map(|(shape, binding)| {
+                        (
+                            binding,
+                            sequence_field(param, fixed, shape, quote!(#binding)),
+                        )
+                    })
+                    .collect();
+                let combined = combine(effects, quote!((#(#bindings,)*)));
+                quote!({ let (#(#bindings,)*) = #e; #combined })
+            }
+            Shape::Container(arg, shape) => {
+                let arg = substitute(
+                    quote!(#arg),
+                    param,
+                    &quote!(<__TA as ::haskell_bits::gat::HigherKinded>::Of<__T>),
+                );
+                let inner = sequence_field(param, fixed, shape, quote!(__y));
+                quote!(::haskell_bits::traverse::<_, __TA, _, _, _, _>(|__y: #arg| #inner, #e))
+            }
+        }
+    }
+
+    // Combines the effects, each named by the binding its result gets, and then `build`s the value
+    // from the bindings. Bindings without an effect are moved into `build` as they are.
+    fn combine(effects: Vec<(&Ident, TokenStream)>, build: TokenStream) -> TokenStream {
+        let lift = quote!(<__TA as ::haskell_bits::Lift>::lift);
+        let llift2 = quote!(<__TA as ::haskell_bits::LinearApplicative>::llift2);
+        let mut effects = effects.into_iter();
+        let (mut pattern, mut acc) = match effects.next() {
+            Some((binding, effect)) => (quote!(#binding), effect),
+            None => return quote!(#lift(#build)),
+        };
+        let rest: Vec<_> = effects.collect();
+        match rest.split_last() {
+            Some(((last, last_effect), middle)) => {
+                for (binding, effect) in middle {
+                    acc = quote!(#llift2(|__a, __b| (__a, __b), #acc, #effect));
+                    pattern = quote!((#pattern, #binding));
+                }
+                quote!(#llift2(move |#pattern, #last| #build, #acc, #last_effect))
+            }
+            None => quote!(<__TA as ::haskell_bits::LinearApplicative>::lap(
+                #lift(move |#pattern| #build),
+                #acc,
+            )),
+        }
+    }
+
+    let body = info.match_arms(|constructor| {
+        let effects = constructor
+            .fields
+            .iter()
+            .filter(|field| !matches!(field.shape, Shape::Fixed))
+            .map(|field| {
+                let binding = &field.binding;
+                (
+                    binding,
+                    sequence_field(param, fixed, &field.shape, quote!(#binding)),
+                )
+            })
+            .collect();
+        combine(
+            effects,
+            constructor.build(|field| {
+                let binding = &field.binding;
+                quote!(#binding)
+            }),
+        )
+    });
+
+    Ok(quote! {
+        impl<#(#fixed),*> ::haskell_bits::LinearTraversable for #con {
+            fn sequence<__TA, __T>(
+                __x: <Self as ::haskell_bits::WithTypeArg<
+                    <__TA as ::haskell_bits::WithTypeArg<__T>>::Type,
+                >>::Type,
+            ) -> <__TA as ::haskell_bits::WithTypeArg<
+                <Self as ::haskell_bits::WithTypeArg<__T>>::Type,
+            >>::Type
+            where
+                __TA: ::haskell_bits::LinearApplicative + ::haskell_bits::gat::HigherKinded,
+            {
+                fn __go<#(#fixed,)* __TA, __T>(
+                    __x: #in_type,
+                ) -> <__TA as ::haskell_bits::gat::HigherKinded>::Of<#out_type>
+                where
+                    __TA: ::haskell_bits::LinearApplicative + ::haskell_bits::gat::HigherKinded,
+                {
+                    #body
+                }
+                __go::<#(#fixed,)* __TA, __T>(__x)
+            }
+        }
+    })
+}
@@ -0,0 +1,137 @@
+use haskell_bits::*;
+
+#[derive(TypeCon, Functor, Foldable, Traversable, Clone, Debug, PartialEq)]
+enum Tree<T> {
+    Leaf,
+    Node(Box<Tree<T>>, T, Box<Tree<T>>),
+}
+
+#[derive(TypeCon, Functor, Foldable, Traversable, Clone, Debug, PartialEq)]
+struct Rose<T> {
+    label: T,
+    children: Vec<Rose<T>>,
+}
+
+#[derive(TypeCon, Functor, Foldable, Traversable, Clone, Debug, PartialEq)]
+struct Tagged<Tag, T> {
+    tag: Tag,
+    value: Option<T>,
+    pair: (T, u32, T),
+}
+
+#[derive(TypeCon, Functor, Foldable, Clone, Debug, PartialEq)]
+#[haskell_bits(param = T)]
+struct Swapped<T, U> {
+    first: T,
+    second: U,
+}
+
+fn node(l: Tree<u32>, x: u32, r: Tree<u32>) -> Tree<u32> {
+    Tree::Node(Box::new(l), x, Box::new(r))
+}
+
+fn leaf(x: u32) -> Tree<u32> {
+    node(Tree::Leaf, x, Tree::Leaf)
+}
+
+#[test]
+fn test_derive_tree() {
+    let t = node(leaf(1), 2, leaf(3));
+
+    assert_eq!(fmap(|x| x * 10, &t), node(leaf(10), 20, leaf(30)));
+    assert_eq!(lmap(|x| x + 1, t.clone()), node(leaf(2), 3, leaf(4)));
+
+    assert_eq!(
+        foldr(
+            |x: &u32, acc: Vec<u32>| {
+                let mut acc = acc;
+                acc.push(*x);
+                acc
+            },
+            Vec::new(),
+            &t
+        ),
+        vec![3, 2, 1]
+    );
+    assert_eq!(lfoldr(|x, acc: u32| x + acc, 0, t.clone()), 6);
+
+    assert_eq!(
+        traverse::<_, OptionTypeCon, _, _, _, _>(
+            |x: u32| if x > 0 { Some(x - 1) } else { None },
+            t.clone()
+        ),
+        Some(node(leaf(0), 1, leaf(2)))
+    );
+    assert_eq!(
+        traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| if x != 2 { Some(x) } else { None }, t),
+        None
+    );
+}
+
+#[test]
+fn test_derive_rose() {
+    let r = Rose {
+        label: 1,
+        children: vec![
+            Rose {
+                label: 2,
+                children: vec![],
+            },
+            Rose {
+                label: 3,
+                children: vec![Rose {
+                    label: 4,
+                    children: vec![],
+                }],
+            },
+        ],
+    };
+
+    let shown = fmap(|x: &u32| x.to_string(), &r);
+    assert_eq!(shown.children[1].children[0].label, "4");
+
+    assert_eq!(lfoldr(|x: u32, acc| x + acc, 0, r.clone()), 10);
+
+    let doubled: Result<Rose<u32>, String> =
+        traverse::<_, ResultTypeCon<_>, _, _, _, _>(|x: u32| Ok(x * 2), r);
+    assert_eq!(doubled.unwrap().children[1].label, 6);
+}
+
+#[test]
+fn test_derive_fixed_params() {
+    let t = Tagged {
+        tag: "tag",
+        value: Some(1),
+        pair: (2, 100, 3),
+    };
+
+    assert_eq!(
+        fmap(|x: &u32| x + 1, &t),
+        Tagged {
+            tag: "tag",
+            value: Some(2),
+            pair: (3, 100, 4)
+        }
+    );
+    assert_eq!(foldr(|x: &u32, acc| x + acc, 0, &t), 6);
+    assert_eq!(
+        traverse::<_, OptionTypeCon, _, _, _, _>(
+            |x: u32| if x < 10 { Some(x) } else { None },
+            t.clone()
+        ),
+        Some(t)
+    );
+
+    let s = Swapped {
+        first: 1,
+        second: "second",
+    };
+    assert_eq!(
+        lmap(|x: u32| x * 2, s.clone()),
+        Swapped {
+            first: 2,
+            second: "second"
+        }
+    );
+    assert_eq!(lfoldr(|x: u32, acc| x + acc, 10, s), 11);
+}
@@ -47,6 +47,170 @@ pub trait LinearApplicative: Lift {
     where
         Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
         TFunc: FnOnce(TIn1, TIn2) -> TOut;
+
+    fn llift3<TIn1, TIn2, TIn3, TOut, TFunc>(
+        f: TFunc,
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+        x3: <Self as WithTypeArg<TIn3>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<TOut>,
+        TFunc: FnOnce(TIn1, TIn2, TIn3) -> TOut,
+    {
+        let t = <Self as LinearApplicative>::llift2(|y1: TIn1, y2: TIn2| (y1, y2), x1, x2);
+        <Self as LinearApplicative>::llift2(|(y1, y2): (TIn1, TIn2), y3: TIn3| f(y1, y2, y3), t, x3)
+    }
+
+    fn llift4<TIn1, TIn2, TIn3, TIn4, TOut, TFunc>(
+        f: TFunc,
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+        x3: <Self as WithTypeArg<TIn3>>::Type,
+        x4: <Self as WithTypeArg<TIn4>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<(TIn1, TIn2, TIn3)>
+            + WithTypeArg<TOut>,
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4) -> TOut,
+    {
+        let t = <Self as LinearApplicative>::llift2(|y1: TIn1, y2: TIn2| (y1, y2), x1, x2);
+        let t = <Self as LinearApplicative>::llift2(
+            |(y1, y2): (TIn1, TIn2), y3: TIn3| (y1, y2, y3),
+            t,
+            x3,
+        );
+        <Self as LinearApplicative>::llift2(
+            |(y1, y2, y3): (TIn1, TIn2, TIn3), y4: TIn4| f(y1, y2, y3, y4),
+            t,
+            x4,
+        )
+    }
+
+    fn llift5<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, TFunc>(
+        f: TFunc,
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+        x3: <Self as WithTypeArg<TIn3>>::Type,
+        x4: <Self as WithTypeArg<TIn4>>::Type,
+        x5: <Self as WithTypeArg<TIn5>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<TIn5>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<(TIn1, TIn2, TIn3)>
+            + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+            + WithTypeArg<TOut>,
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5) -> TOut,
+    {
+        let t = <Self as LinearApplicative>::llift2(|y1: TIn1, y2: TIn2| (y1, y2), x1, x2);
+        let t = <Self as LinearApplicative>::llift2(
+            |(y1, y2): (TIn1, TIn2), y3: TIn3| (y1, y2, y3),
+            t,
+            x3,
+        );
+        let t = <Self as LinearApplicative>::llift2(
+            |(y1, y2, y3): (TIn1, TIn2, TIn3), y4: TIn4| (y1, y2, y3, y4),
+            t,
+            x4,
+        );
+        <Self as LinearApplicative>::llift2(
+            |(y1, y2, y3, y4): (TIn1, TIn2, TIn3, TIn4), y5: TIn5| f(y1, y2, y3, y4, y5),
+            t,
+            x5,
+        )
+    }
+
+    fn llift6<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, TFunc>(
+        f: TFunc,
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+        x3: <Self as WithTypeArg<TIn3>>::Type,
+        x4: <Self as WithTypeArg<TIn4>>::Type,
+        x5: <Self as WithTypeArg<TIn5>>::Type,
+        x6: <Self as WithTypeArg<TIn6>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<TIn5>
+            + WithTypeArg<TIn6>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<(TIn1, TIn2, TIn3)>
+            + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+            + WithTypeArg<(TIn1, TIn2, TIn3, TIn4, TIn5)>
+            + WithTypeArg<TOut>,
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5, TIn6) -> TOut,
+    {
+        let t = <Self as LinearApplicative>::llift2(|y1: TIn1, y2: TIn2| (y1, y2), x1, x2);
+        let t = <Self as LinearApplicative>::llift2(
+            |(y1, y2): (TIn1, TIn2), y3: TIn3| (y1, y2, y3),
+            t,
+            x3,
+        );
+        let t = <Self as LinearApplicative>::llift2(
+            |(y1, y2, y3): (TIn1, TIn2, TIn3), y4: TIn4| (y1, y2, y3, y4),
+            t,
+            x4,
+        );
+        let t = <Self as LinearApplicative>::llift2(
+            |(y1, y2, y3, y4): (TIn1, TIn2, TIn3, TIn4), y5: TIn5| (y1, y2, y3, y4, y5),
+            t,
+            x5,
+        );
+        <Self as LinearApplicative>::llift2(
+            |(y1, y2, y3, y4, y5): (TIn1, TIn2, TIn3, TIn4, TIn5), y6: TIn6| {
+                f(y1, y2, y3, y4, y5, y6)
+            },
+            t,
+            x6,
+        )
+    }
+
+    fn lseq_left<TIn1, TIn2>(
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TIn1>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2>,
+    {
+        <Self as LinearApplicative>::llift2(|y1: TIn1, _: TIn2| y1, x1, x2)
+    }
+
+    fn lseq_right<TIn1, TIn2>(
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TIn2>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2>,
+    {
+        <Self as LinearApplicative>::llift2(|_: TIn1, y2: TIn2| y2, x1, x2)
+    }
+
+    fn lzip_a<TIn1, TIn2>(
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<(TIn1, TIn2)>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<(TIn1, TIn2)>,
+    {
+        <Self as LinearApplicative>::llift2(|y1: TIn1, y2: TIn2| (y1, y2), x1, x2)
+    }
 }
 
 pub fn lap<TCon, TIn, TOut, TFunc, F, X>(
@@ -81,6 +245,262 @@ where
     <TCon as LinearApplicative>::llift2(f, x1.into_val(), x2.into_val())
 }
 
+pub fn llift3<TCon, TIn1, TIn2, TIn3, TOut, F, X1, X2, X3>(
+    f: F,
+    x1: X1,
+    x2: X2,
+    x3: X3,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearApplicative
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: FnOnce(TIn1, TIn2, TIn3) -> TOut,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+    X3: TypeApp<TCon, TIn3>,
+{
+    <TCon as LinearApplicative>::llift3(f, x1.into_val(), x2.into_val(), x3.into_val())
+}
+
+pub fn llift4<TCon, TIn1, TIn2, TIn3, TIn4, TOut, F, X1, X2, X3, X4>(
+    f: F,
+    x1: X1,
+    x2: X2,
+    x3: X3,
+    x4: X4,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearApplicative
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<(TIn1, TIn2, TIn3)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: FnOnce(TIn1, TIn2, TIn3, TIn4) -> TOut,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+    X3: TypeApp<TCon, TIn3>,
+    X4: TypeApp<TCon, TIn4>,
+{
+    <TCon as LinearApplicative>::llift4(
+        f,
+        x1.into_val(),
+        x2.into_val(),
+        x3.into_val(),
+        x4.into_val(),
+    )
+}
+
+pub fn llift5<TCon, TIn1, TIn2, TIn3, TIn4, TIn5, TOut, F, X1, X2, X3, X4, X5>(
+    f: F,
+    x1: X1,
+    x2: X2,
+    x3: X3,
+    x4: X4,
+    x5: X5,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearApplicative
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<TIn5>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<(TIn1, TIn2, TIn3)>
+        + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5) -> TOut,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+    X3: TypeApp<TCon, TIn3>,
+    X4: TypeApp<TCon, TIn4>,
+    X5: TypeApp<TCon, TIn5>,
+{
+    <TCon as LinearApplicative>::llift5(
+        f,
+        x1.into_val(),
+        x2.into_val(),
+        x3.into_val(),
+        x4.into_val(),
+        x5.into_val(),
+    )
+}
+
+pub fn llift6<TCon, TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, F, X1, X2, X3, X4, X5, X6>(
+    f: F,
+    x1: X1,
+    x2: X2,
+    x3: X3,
+    x4: X4,
+    x5: X5,
+    x6: X6,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearApplicative
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<TIn5>
+        + WithTypeArg<TIn6>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<(TIn1, TIn2, TIn3)>
+        + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+        + WithTypeArg<(TIn1, TIn2, TIn3, TIn4, TIn5)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5, TIn6) -> TOut,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+    X3: TypeApp<TCon, TIn3>,
+    X4: TypeApp<TCon, TIn4>,
+    X5: TypeApp<TCon, TIn5>,
+    X6: TypeApp<TCon, TIn6>,
+{
+    <TCon as LinearApplicative>::llift6(
+        f,
+        x1.into_val(),
+        x2.into_val(),
+        x3.into_val(),
+        x4.into_val(),
+        x5.into_val(),
+        x6.into_val(),
+    )
+}
+
+pub fn lseq_left<TCon, TIn1, TIn2, X1, X2>(x1: X1, x2: X2) -> <TCon as WithTypeArg<TIn1>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + ?Sized,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+{
+    <TCon as LinearApplicative>::lseq_left::<TIn1, TIn2>(x1.into_val(), x2.into_val())
+}
+
+pub fn lseq_right<TCon, TIn1, TIn2, X1, X2>(x1: X1, x2: X2) -> <TCon as WithTypeArg<TIn2>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + ?Sized,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+{
+    <TCon as LinearApplicative>::lseq_right::<TIn1, TIn2>(x1.into_val(), x2.into_val())
+}
+
+pub fn lzip_a<TCon, TIn1, TIn2, X1, X2>(
+    x1: X1,
+    x2: X2,
+) -> <TCon as WithTypeArg<(TIn1, TIn2)>>::Type
+where
+    TCon: LinearApplicative
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<(TIn1, TIn2)>
+        + ?Sized,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+{
+    <TCon as LinearApplicative>::lzip_a::<TIn1, TIn2>(x1.into_val(), x2.into_val())
+}
+
+// LinearApplicative takes FnOnce functions, so it can only be implemented by types which
+// hold at most one value, like Option and Result.
+//
+// Types like Vec need to call the function once for each combination of values,
+// so they implement this instead, which takes FnMut functions and clones the values
+// only when they need to be used more than once.
+//
+// Every LinearApplicative is also a LinearApplicativeMut.
+pub trait LinearApplicativeMut: Lift {
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <Self as WithTypeArg<TFunc>>::Type,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TFunc> + WithTypeArg<TIn> + WithTypeArg<TOut>,
+        TFunc: FnMut(TIn) -> TOut,
+        TIn: Clone;
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone;
+}
+
+impl<TCon> LinearApplicativeMut for TCon
+where
+    TCon: LinearApplicative + ?Sized,
+{
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <TCon as WithTypeArg<TFunc>>::Type,
+        x: <TCon as WithTypeArg<TIn>>::Type,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TFunc> + WithTypeArg<TIn> + WithTypeArg<TOut>,
+        TFunc: FnMut(TIn) -> TOut,
+    {
+        <TCon as LinearApplicative>::lap::<TIn, TOut, TFunc>(f, x)
+    }
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: <TCon as WithTypeArg<TIn1>>::Type,
+        x2: <TCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+    {
+        <TCon as LinearApplicative>::llift2(f, x1, x2)
+    }
+}
+
+pub fn lap_mut<TCon, TIn, TOut, TFunc, F, X>(f: F, x: X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearApplicativeMut
+        + WithTypeArg<TFunc>
+        + WithTypeArg<TIn>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    TFunc: FnMut(TIn) -> TOut,
+    TIn: Clone,
+    F: TypeApp<TCon, TFunc>,
+    X: TypeApp<TCon, TIn>,
+{
+    <TCon as LinearApplicativeMut>::lap_mut::<TIn, TOut, TFunc>(f.into_val(), x.into_val())
+}
+
+pub fn llift2_mut<TCon, TIn1, TIn2, TOut, F, X1, X2>(
+    f: F,
+    x1: X1,
+    x2: X2,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearApplicativeMut + WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut> + ?Sized,
+    F: FnMut(TIn1, TIn2) -> TOut,
+    TIn1: Clone,
+    TIn2: Clone,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+{
+    <TCon as LinearApplicativeMut>::llift2_mut(f, x1.into_val(), x2.into_val())
+}
+
 pub trait Applicative: Functor + Lift {
     fn ap<TIn, TOut, TFunc>(
         f: &<Self as WithTypeArg<TFunc>>::Type,
@@ -101,6 +521,352 @@ pub trait Applicative: Functor + Lift {
     where
         Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
         F: Fn(&TIn1, &TIn2) -> TOut;
+
+    // x1 <* x2
+    fn seq_left<TIn1, TIn2>(
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TIn1>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2>,
+        TIn1: Clone,
+    {
+        <Self as Applicative>::lift2(|y1: &TIn1, _: &TIn2| y1.clone(), x1, x2)
+    }
+
+    // x1 *> x2
+    fn seq_right<TIn1, TIn2>(
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TIn2>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2>,
+        TIn2: Clone,
+    {
+        <Self as Applicative>::lift2(|_: &TIn1, y2: &TIn2| y2.clone(), x1, x2)
+    }
+
+    // ap_first is another name for seq_left
+    fn ap_first<TIn1, TIn2>(
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TIn1>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2>,
+        TIn1: Clone,
+    {
+        <Self as Applicative>::seq_left::<TIn1, TIn2>(x1, x2)
+    }
+
+    // ap_second is another name for seq_right
+    fn ap_second<TIn1, TIn2>(
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TIn2>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2>,
+        TIn2: Clone,
+    {
+        <Self as Applicative>::seq_right::<TIn1, TIn2>(x1, x2)
+    }
+
+    fn zip_a<TIn1, TIn2>(
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<(TIn1, TIn2)>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<(TIn1, TIn2)>,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        <Self as Applicative>::lift2(|y1: &TIn1, y2: &TIn2| (y1.clone(), y2.clone()), x1, x2)
+    }
+}
+
+// lift3 to lift6, for applicatives which can pass all the values straight to `f`, like Option,
+// Result and Vec. These put no bounds on the values.
+//
+// Any other Applicative can still combine more than two values with `ApplicativeClone`.
+pub trait ApplicativeN: Applicative {
+    fn lift3<TIn1, TIn2, TIn3, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TIn3> + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3) -> TOut;
+
+    fn lift4<TIn1, TIn2, TIn3, TIn4, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+        x4: &<Self as WithTypeArg<TIn4>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3, &TIn4) -> TOut;
+
+    fn lift5<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+        x4: &<Self as WithTypeArg<TIn4>>::Type,
+        x5: &<Self as WithTypeArg<TIn5>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<TIn5>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5) -> TOut;
+
+    fn lift6<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+        x4: &<Self as WithTypeArg<TIn4>>::Type,
+        x5: &<Self as WithTypeArg<TIn5>>::Type,
+        x6: &<Self as WithTypeArg<TIn6>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<TIn5>
+            + WithTypeArg<TIn6>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5, &TIn6) -> TOut;
+}
+
+// lift3_clone to lift6_clone, which work for any Applicative by pairing up the values with
+// `lift2`. As `lift2` only lends `f` the values, this has to copy all but the last of them.
+//
+// Every Applicative is also an ApplicativeClone.
+pub trait ApplicativeClone: Applicative {
+    fn lift3_clone<TIn1, TIn2, TIn3, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        let t =
+            <Self as Applicative>::lift2(|y1: &TIn1, y2: &TIn2| (y1.clone(), y2.clone()), x1, x2);
+        <Self as Applicative>::lift2(|t: &(TIn1, TIn2), y3: &TIn3| f(&t.0, &t.1, y3), &t, x3)
+    }
+
+    fn lift4_clone<TIn1, TIn2, TIn3, TIn4, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+        x4: &<Self as WithTypeArg<TIn4>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<(TIn1, TIn2, TIn3)>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3, &TIn4) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+        TIn3: Clone,
+    {
+        let t =
+            <Self as Applicative>::lift2(|y1: &TIn1, y2: &TIn2| (y1.clone(), y2.clone()), x1, x2);
+        let t = <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2), y3: &TIn3| (t.0.clone(), t.1.clone(), y3.clone()),
+            &t,
+            x3,
+        );
+        <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2, TIn3), y4: &TIn4| f(&t.0, &t.1, &t.2, y4),
+            &t,
+            x4,
+        )
+    }
+
+    fn lift5_clone<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+        x4: &<Self as WithTypeArg<TIn4>>::Type,
+        x5: &<Self as WithTypeArg<TIn5>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<TIn5>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<(TIn1, TIn2, TIn3)>
+            + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+        TIn3: Clone,
+        TIn4: Clone,
+    {
+        let t =
+            <Self as Applicative>::lift2(|y1: &TIn1, y2: &TIn2| (y1.clone(), y2.clone()), x1, x2);
+        let t = <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2), y3: &TIn3| (t.0.clone(), t.1.clone(), y3.clone()),
+            &t,
+            x3,
+        );
+        let t = <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2, TIn3), y4: &TIn4| (t.0.clone(), t.1.clone(), t.2.clone(), y4.clone()),
+            &t,
+            x4,
+        );
+        <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2, TIn3, TIn4), y5: &TIn5| f(&t.0, &t.1, &t.2, &t.3, y5),
+            &t,
+            x5,
+        )
+    }
+
+    fn lift6_clone<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+        x3: &<Self as WithTypeArg<TIn3>>::Type,
+        x4: &<Self as WithTypeArg<TIn4>>::Type,
+        x5: &<Self as WithTypeArg<TIn5>>::Type,
+        x6: &<Self as WithTypeArg<TIn6>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TIn3>
+            + WithTypeArg<TIn4>
+            + WithTypeArg<TIn5>
+            + WithTypeArg<TIn6>
+            + WithTypeArg<(TIn1, TIn2)>
+            + WithTypeArg<(TIn1, TIn2, TIn3)>
+            + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+            + WithTypeArg<(TIn1, TIn2, TIn3, TIn4, TIn5)>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5, &TIn6) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+        TIn3: Clone,
+        TIn4: Clone,
+        TIn5: Clone,
+    {
+        let t =
+            <Self as Applicative>::lift2(|y1: &TIn1, y2: &TIn2| (y1.clone(), y2.clone()), x1, x2);
+        let t = <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2), y3: &TIn3| (t.0.clone(), t.1.clone(), y3.clone()),
+            &t,
+            x3,
+        );
+        let t = <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2, TIn3), y4: &TIn4| (t.0.clone(), t.1.clone(), t.2.clone(), y4.clone()),
+            &t,
+            x4,
+        );
+        let t = <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2, TIn3, TIn4), y5: &TIn5| {
+                (
+                    t.0.clone(),
+                    t.1.clone(),
+                    t.2.clone(),
+                    t.3.clone(),
+                    y5.clone(),
+                )
+            },
+            &t,
+            x5,
+        );
+        <Self as Applicative>::lift2(
+            |t: &(TIn1, TIn2, TIn3, TIn4, TIn5), y6: &TIn6| f(&t.0, &t.1, &t.2, &t.3, &t.4, y6),
+            &t,
+            x6,
+        )
+    }
+}
+
+impl<TCon> ApplicativeClone for TCon where TCon: Applicative + ?Sized {}
+
+// Applicative for types which are only a SharedFunctor, like Rc and Arc.
+//
+// Every Applicative is also a SharedApplicative.
+pub trait SharedApplicative: SharedFunctor + Lift {
+    fn ap_shared<TIn, TOut, TFunc>(
+        f: &<Self as WithTypeArg<TFunc>>::Type,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TFunc> + WithTypeArg<TIn> + WithTypeArg<TOut>,
+        TFunc: Fn(&TIn) -> TOut,
+    {
+        <Self as SharedApplicative>::lift2_shared(|y1: &TFunc, y2: &TIn| y1(y2), f, x)
+    }
+
+    fn lift2_shared<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2) -> TOut;
+}
+
+impl<TCon> SharedApplicative for TCon
+where
+    TCon: Applicative + ?Sized,
+{
+    fn ap_shared<TIn, TOut, TFunc>(
+        f: &<TCon as WithTypeArg<TFunc>>::Type,
+        x: &<TCon as WithTypeArg<TIn>>::Type,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TFunc> + WithTypeArg<TIn> + WithTypeArg<TOut>,
+        TFunc: Fn(&TIn) -> TOut,
+    {
+        <TCon as Applicative>::ap::<TIn, TOut, TFunc>(f, x)
+    }
+
+    fn lift2_shared<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<TCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        <TCon as Applicative>::lift2(f, x1, x2)
+    }
 }
 
 // fap(f, x)
@@ -109,12 +875,12 @@ pub fn ap<TCon, TFunc, TIn, TOut, F, X>(
     x: &X,
 ) -> <TCon as WithTypeArg<TOut>>::Type
 where
-    TCon: Applicative + WithTypeArg<TFunc> + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    TCon: SharedApplicative + WithTypeArg<TFunc> + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
     TFunc: Fn(&TIn) -> TOut,
     F: TypeApp<TCon, TFunc> + ?Sized,
     X: TypeApp<TCon, TIn> + ?Sized,
 {
-    <TCon as Applicative>::ap::<TIn, TOut, TFunc>(f.into_ref(), x.into_ref())
+    <TCon as SharedApplicative>::ap_shared::<TIn, TOut, TFunc>(f.into_ref(), x.into_ref())
 }
 
 pub fn lift2<TCon, TIn1, TIn2, TOut, F, X1, X2>(
@@ -123,10 +889,398 @@ pub fn lift2<TCon, TIn1, TIn2, TOut, F, X1, X2>(
     x2: &X2,
 ) -> <TCon as WithTypeArg<TOut>>::Type
 where
-    TCon: Applicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut> + ?Sized,
+    TCon: SharedApplicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&TIn1, &TIn2) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+{
+    <TCon as SharedApplicative>::lift2_shared(f, x1.into_ref(), x2.into_ref())
+}
+
+pub fn lift3<TCon, TIn1, TIn2, TIn3, TOut, F, X1, X2, X3>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeN
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+{
+    <TCon as ApplicativeN>::lift3(f, x1.into_ref(), x2.into_ref(), x3.into_ref())
+}
+
+pub fn lift4<TCon, TIn1, TIn2, TIn3, TIn4, TOut, F, X1, X2, X3, X4>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+    x4: &X4,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeN
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3, &TIn4) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+    X4: TypeApp<TCon, TIn4> + ?Sized,
+{
+    <TCon as ApplicativeN>::lift4(
+        f,
+        x1.into_ref(),
+        x2.into_ref(),
+        x3.into_ref(),
+        x4.into_ref(),
+    )
+}
+
+pub fn lift5<TCon, TIn1, TIn2, TIn3, TIn4, TIn5, TOut, F, X1, X2, X3, X4, X5>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+    x4: &X4,
+    x5: &X5,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeN
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<TIn5>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+    X4: TypeApp<TCon, TIn4> + ?Sized,
+    X5: TypeApp<TCon, TIn5> + ?Sized,
+{
+    <TCon as ApplicativeN>::lift5(
+        f,
+        x1.into_ref(),
+        x2.into_ref(),
+        x3.into_ref(),
+        x4.into_ref(),
+        x5.into_ref(),
+    )
+}
+
+pub fn lift6<TCon, TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, F, X1, X2, X3, X4, X5, X6>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+    x4: &X4,
+    x5: &X5,
+    x6: &X6,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeN
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<TIn5>
+        + WithTypeArg<TIn6>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5, &TIn6) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+    X4: TypeApp<TCon, TIn4> + ?Sized,
+    X5: TypeApp<TCon, TIn5> + ?Sized,
+    X6: TypeApp<TCon, TIn6> + ?Sized,
+{
+    <TCon as ApplicativeN>::lift6(
+        f,
+        x1.into_ref(),
+        x2.into_ref(),
+        x3.into_ref(),
+        x4.into_ref(),
+        x5.into_ref(),
+        x6.into_ref(),
+    )
+}
+
+pub fn lift3_clone<TCon, TIn1, TIn2, TIn3, TOut, F, X1, X2, X3>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeClone
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+    TIn1: Clone,
+    TIn2: Clone,
+{
+    <TCon as ApplicativeClone>::lift3_clone(f, x1.into_ref(), x2.into_ref(), x3.into_ref())
+}
+
+pub fn lift4_clone<TCon, TIn1, TIn2, TIn3, TIn4, TOut, F, X1, X2, X3, X4>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+    x4: &X4,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeClone
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<(TIn1, TIn2, TIn3)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3, &TIn4) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+    X4: TypeApp<TCon, TIn4> + ?Sized,
+    TIn1: Clone,
+    TIn2: Clone,
+    TIn3: Clone,
+{
+    <TCon as ApplicativeClone>::lift4_clone(
+        f,
+        x1.into_ref(),
+        x2.into_ref(),
+        x3.into_ref(),
+        x4.into_ref(),
+    )
+}
+
+pub fn lift5_clone<TCon, TIn1, TIn2, TIn3, TIn4, TIn5, TOut, F, X1, X2, X3, X4, X5>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+    x4: &X4,
+    x5: &X5,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeClone
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<TIn5>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<(TIn1, TIn2, TIn3)>
+        + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+    X4: TypeApp<TCon, TIn4> + ?Sized,
+    X5: TypeApp<TCon, TIn5> + ?Sized,
+    TIn1: Clone,
+    TIn2: Clone,
+    TIn3: Clone,
+    TIn4: Clone,
+{
+    <TCon as ApplicativeClone>::lift5_clone(
+        f,
+        x1.into_ref(),
+        x2.into_ref(),
+        x3.into_ref(),
+        x4.into_ref(),
+        x5.into_ref(),
+    )
+}
+
+pub fn lift6_clone<TCon, TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, F, X1, X2, X3, X4, X5, X6>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+    x3: &X3,
+    x4: &X4,
+    x5: &X5,
+    x6: &X6,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: ApplicativeClone
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TIn3>
+        + WithTypeArg<TIn4>
+        + WithTypeArg<TIn5>
+        + WithTypeArg<TIn6>
+        + WithTypeArg<(TIn1, TIn2)>
+        + WithTypeArg<(TIn1, TIn2, TIn3)>
+        + WithTypeArg<(TIn1, TIn2, TIn3, TIn4)>
+        + WithTypeArg<(TIn1, TIn2, TIn3, TIn4, TIn5)>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5, &TIn6) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    X3: TypeApp<TCon, TIn3> + ?Sized,
+    X4: TypeApp<TCon, TIn4> + ?Sized,
+    X5: TypeApp<TCon, TIn5> + ?Sized,
+    X6: TypeApp<TCon, TIn6> + ?Sized,
+    TIn1: Clone,
+    TIn2: Clone,
+    TIn3: Clone,
+    TIn4: Clone,
+    TIn5: Clone,
+{
+    <TCon as ApplicativeClone>::lift6_clone(
+        f,
+        x1.into_ref(),
+        x2.into_ref(),
+        x3.into_ref(),
+        x4.into_ref(),
+        x5.into_ref(),
+        x6.into_ref(),
+    )
+}
+
+// seq_left(x1, x2), which is Haskell's (x1 <* x2)
+pub fn seq_left<TCon, TIn1, TIn2, X1, X2>(x1: &X1, x2: &X2) -> <TCon as WithTypeArg<TIn1>>::Type
+where
+    TCon: Applicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + ?Sized,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    TIn1: Clone,
+{
+    <TCon as Applicative>::seq_left::<TIn1, TIn2>(x1.into_ref(), x2.into_ref())
+}
+
+// seq_right(x1, x2), which is Haskell's (x1 *> x2)
+pub fn seq_right<TCon, TIn1, TIn2, X1, X2>(
+    x1: &X1,
+    x2: &X2,
+) -> <TCon as WithTypeArg<TIn2>>::Type
+where
+    TCon: Applicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + ?Sized,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    TIn2: Clone,
+{
+    <TCon as Applicative>::seq_right::<TIn1, TIn2>(x1.into_ref(), x2.into_ref())
+}
+
+// ap_first is another name for seq_left
+pub fn ap_first<TCon, TIn1, TIn2, X1, X2>(x1: &X1, x2: &X2) -> <TCon as WithTypeArg<TIn1>>::Type
+where
+    TCon: Applicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + ?Sized,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    TIn1: Clone,
+{
+    <TCon as Applicative>::ap_first::<TIn1, TIn2>(x1.into_ref(), x2.into_ref())
+}
+
+// ap_second is another name for seq_right
+pub fn ap_second<TCon, TIn1, TIn2, X1, X2>(
+    x1: &X1,
+    x2: &X2,
+) -> <TCon as WithTypeArg<TIn2>>::Type
+where
+    TCon: Applicative + WithTypeArg<TIn1> + WithTypeArg<TIn2> + ?Sized,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    TIn2: Clone,
+{
+    <TCon as Applicative>::ap_second::<TIn1, TIn2>(x1.into_ref(), x2.into_ref())
+}
+
+pub fn zip_a<TCon, TIn1, TIn2, X1, X2>(
+    x1: &X1,
+    x2: &X2,
+) -> <TCon as WithTypeArg<(TIn1, TIn2)>>::Type
+where
+    TCon: Applicative
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<(TIn1, TIn2)>
+        + ?Sized,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+    TIn1: Clone,
+    TIn2: Clone,
+{
+    <TCon as Applicative>::zip_a::<TIn1, TIn2>(x1.into_ref(), x2.into_ref())
+}
+
+// Apply is Applicative without `lift`, i.e. Haskell's `Apply` from semigroupoids.
+//
+// Some types can combine two values of the same shape but can't create one from scratch,
+// like fixed size arrays (which would need `N` copies of the value, but `lift` doesn't
+// require `Clone`), or maps (where `lift` would need to know which keys to use).
+//
+// Every Applicative is also an Apply, with `lift_f2` being `lift2`.
+pub trait Apply: Functor {
+    fn lift_f2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2) -> TOut;
+}
+
+impl<TCon> Apply for TCon
+where
+    TCon: Applicative + ?Sized,
+{
+    fn lift_f2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<TCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        <TCon as Applicative>::lift2(f, x1, x2)
+    }
+}
+
+// lift_f2(f, x1, x2)
+pub fn lift_f2<TCon, TIn1, TIn2, TOut, F, X1, X2>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: Apply + WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut> + ?Sized,
     F: Fn(&TIn1, &TIn2) -> TOut,
     X1: TypeApp<TCon, TIn1> + ?Sized,
     X2: TypeApp<TCon, TIn2> + ?Sized,
 {
-    <TCon as Applicative>::lift2(f, x1.into_ref(), x2.into_ref())
+    <TCon as Apply>::lift_f2(f, x1.into_ref(), x2.into_ref())
 }
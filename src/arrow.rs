@@ -0,0 +1,271 @@
+use crate::*;
+
+// A Category has identity arrows and composes arrows end to end, like functions.
+// `compose(f, g)` runs `g` and then `f`, as in Haskell, and `and_then(f, g)` runs `f` and then
+// `g`, which reads better for pipelines.
+//
+// Like the profunctors, the instances hold boxed functions, so the types involved have to be
+// `'static`.
+pub trait Category {
+    fn id<A>() -> <Self as WithTypeArgs2<A, A>>::Type
+    where
+        Self: WithTypeArgs2<A, A>,
+        A: 'static;
+
+    fn compose<A, B, C>(
+        f: <Self as WithTypeArgs2<B, C>>::Type,
+        g: <Self as WithTypeArgs2<A, B>>::Type,
+    ) -> <Self as WithTypeArgs2<A, C>>::Type
+    where
+        Self: WithTypeArgs2<A, B> + WithTypeArgs2<B, C> + WithTypeArgs2<A, C>,
+        A: 'static,
+        B: 'static,
+        C: 'static;
+}
+
+// id::<TCon, _>()
+pub fn id<TCon, A>() -> <TCon as WithTypeArgs2<A, A>>::Type
+where
+    TCon: Category + WithTypeArgs2<A, A> + ?Sized,
+    A: 'static,
+{
+    <TCon as Category>::id()
+}
+
+// compose(f, g)
+pub fn compose<TCon, A, B, C, X, Y>(f: X, g: Y) -> <TCon as WithTypeArgs2<A, C>>::Type
+where
+    TCon: Category + WithTypeArgs2<A, B> + WithTypeArgs2<B, C> + WithTypeArgs2<A, C> + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    X: TypeApp2<TCon, B, C>,
+    Y: TypeApp2<TCon, A, B>,
+{
+    <TCon as Category>::compose::<A, B, C>(f.into_val(), g.into_val())
+}
+
+// and_then(f, g)
+pub fn and_then<TCon, A, B, C, X, Y>(f: X, g: Y) -> <TCon as WithTypeArgs2<A, C>>::Type
+where
+    TCon: Category + WithTypeArgs2<A, B> + WithTypeArgs2<B, C> + WithTypeArgs2<A, C> + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    X: TypeApp2<TCon, A, B>,
+    Y: TypeApp2<TCon, B, C>,
+{
+    <TCon as Category>::compose::<A, B, C>(g.into_val(), f.into_val())
+}
+
+// An Arrow is a Category which can lift plain functions with `arr`, and work on pairs.
+//
+// Its `first` and `second` are the ones from `Strong`, which every Arrow is. That means the
+// values passed alongside have to be `Clone`, and so do the ones `split` and `fanout` pass
+// alongside while they run each side.
+pub trait Arrow: Category + Strong {
+    fn arr<A, B, F>(f: F) -> <Self as WithTypeArgs2<A, B>>::Type
+    where
+        Self: WithTypeArgs2<A, B>,
+        F: Fn(A) -> B + 'static,
+        A: 'static,
+        B: 'static;
+
+    // Haskell's `***`
+    fn split<A, B, C, D>(
+        f: <Self as WithTypeArgs2<A, B>>::Type,
+        g: <Self as WithTypeArgs2<C, D>>::Type,
+    ) -> <Self as WithTypeArgs2<(A, C), (B, D)>>::Type
+    where
+        Self: WithTypeArgs2<A, B>
+            + WithTypeArgs2<C, D>
+            + WithTypeArgs2<(A, C), (B, C)>
+            + WithTypeArgs2<(B, C), (B, D)>
+            + WithTypeArgs2<(C, B), (D, B)>
+            + WithTypeArgs2<(A, C), (B, D)>,
+        A: 'static,
+        B: Clone + 'static,
+        C: Clone + 'static,
+        D: 'static,
+    {
+        <Self as Category>::compose::<(A, C), (B, C), (B, D)>(
+            <Self as Strong>::second::<C, D, B>(g),
+            <Self as Strong>::first::<A, B, C>(f),
+        )
+    }
+
+    // Haskell's `&&&`
+    fn fanout<A, B, C>(
+        f: <Self as WithTypeArgs2<A, B>>::Type,
+        g: <Self as WithTypeArgs2<A, C>>::Type,
+    ) -> <Self as WithTypeArgs2<A, (B, C)>>::Type
+    where
+        Self: WithTypeArgs2<A, B>
+            + WithTypeArgs2<A, C>
+            + WithTypeArgs2<A, (A, A)>
+            + WithTypeArgs2<(A, A), (B, A)>
+            + WithTypeArgs2<(B, A), (B, C)>
+            + WithTypeArgs2<(A, B), (C, B)>
+            + WithTypeArgs2<(A, A), (B, C)>
+            + WithTypeArgs2<A, (B, C)>,
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: 'static,
+    {
+        <Self as Category>::compose::<A, (A, A), (B, C)>(
+            <Self as Arrow>::split::<A, B, A, C>(f, g),
+            <Self as Arrow>::arr(|x: A| (x.clone(), x)),
+        )
+    }
+}
+
+// arr::<TCon, _, _, _>(f)
+pub fn arr<TCon, A, B, F>(f: F) -> <TCon as WithTypeArgs2<A, B>>::Type
+where
+    TCon: Arrow + WithTypeArgs2<A, B> + ?Sized,
+    F: Fn(A) -> B + 'static,
+    A: 'static,
+    B: 'static,
+{
+    <TCon as Arrow>::arr(f)
+}
+
+// split(f, g)
+pub fn split<TCon, A, B, C, D, X, Y>(f: X, g: Y) -> <TCon as WithTypeArgs2<(A, C), (B, D)>>::Type
+where
+    TCon: Arrow
+        + WithTypeArgs2<A, B>
+        + WithTypeArgs2<C, D>
+        + WithTypeArgs2<(A, C), (B, C)>
+        + WithTypeArgs2<(B, C), (B, D)>
+        + WithTypeArgs2<(C, B), (D, B)>
+        + WithTypeArgs2<(A, C), (B, D)>
+        + ?Sized,
+    A: 'static,
+    B: Clone + 'static,
+    C: Clone + 'static,
+    D: 'static,
+    X: TypeApp2<TCon, A, B>,
+    Y: TypeApp2<TCon, C, D>,
+{
+    <TCon as Arrow>::split(f.into_val(), g.into_val())
+}
+
+// fanout(f, g)
+pub fn fanout<TCon, A, B, C, X, Y>(f: X, g: Y) -> <TCon as WithTypeArgs2<A, (B, C)>>::Type
+where
+    TCon: Arrow
+        + WithTypeArgs2<A, B>
+        + WithTypeArgs2<A, C>
+        + WithTypeArgs2<A, (A, A)>
+        + WithTypeArgs2<(A, A), (B, A)>
+        + WithTypeArgs2<(B, A), (B, C)>
+        + WithTypeArgs2<(A, B), (C, B)>
+        + WithTypeArgs2<(A, A), (B, C)>
+        + WithTypeArgs2<A, (B, C)>
+        + ?Sized,
+    A: Clone + 'static,
+    B: Clone + 'static,
+    C: 'static,
+    X: TypeApp2<TCon, A, B>,
+    Y: TypeApp2<TCon, A, C>,
+{
+    <TCon as Arrow>::fanout(f.into_val(), g.into_val())
+}
+
+// An ArrowChoice can branch, running one of two arrows depending on which side of an `Either`
+// its input is. `left` and `right` are the ones from `Choice`.
+pub trait ArrowChoice: Arrow + Choice {
+    // Haskell's `+++`
+    fn split_either<A, B, C, D>(
+        f: <Self as WithTypeArgs2<A, B>>::Type,
+        g: <Self as WithTypeArgs2<C, D>>::Type,
+    ) -> <Self as WithTypeArgs2<Either<A, C>, Either<B, D>>>::Type
+    where
+        Self: WithTypeArgs2<A, B>
+            + WithTypeArgs2<C, D>
+            + WithTypeArgs2<Either<A, C>, Either<B, C>>
+            + WithTypeArgs2<Either<C, B>, Either<D, B>>
+            + WithTypeArgs2<Either<B, C>, Either<B, D>>
+            + WithTypeArgs2<Either<A, C>, Either<B, D>>,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        <Self as Category>::compose::<Either<A, C>, Either<B, C>, Either<B, D>>(
+            <Self as Choice>::right::<C, D, B>(g),
+            <Self as Choice>::left::<A, B, C>(f),
+        )
+    }
+
+    // Haskell's `|||`
+    fn fanin<A, B, C>(
+        f: <Self as WithTypeArgs2<A, C>>::Type,
+        g: <Self as WithTypeArgs2<B, C>>::Type,
+    ) -> <Self as WithTypeArgs2<Either<A, B>, C>>::Type
+    where
+        Self: WithTypeArgs2<A, C>
+            + WithTypeArgs2<B, C>
+            + WithTypeArgs2<Either<A, B>, Either<C, B>>
+            + WithTypeArgs2<Either<B, C>, Either<C, C>>
+            + WithTypeArgs2<Either<C, B>, Either<C, C>>
+            + WithTypeArgs2<Either<A, B>, Either<C, C>>
+            + WithTypeArgs2<Either<C, C>, C>
+            + WithTypeArgs2<Either<A, B>, C>,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        <Self as Category>::compose::<Either<A, B>, Either<C, C>, C>(
+            <Self as Arrow>::arr(|x: Either<C, C>| x.either(|y| y, |y| y)),
+            <Self as ArrowChoice>::split_either::<A, C, B, C>(f, g),
+        )
+    }
+}
+
+// split_either(f, g)
+pub fn split_either<TCon, A, B, C, D, X, Y>(
+    f: X,
+    g: Y,
+) -> <TCon as WithTypeArgs2<Either<A, C>, Either<B, D>>>::Type
+where
+    TCon: ArrowChoice
+        + WithTypeArgs2<A, B>
+        + WithTypeArgs2<C, D>
+        + WithTypeArgs2<Either<A, C>, Either<B, C>>
+        + WithTypeArgs2<Either<C, B>, Either<D, B>>
+        + WithTypeArgs2<Either<B, C>, Either<B, D>>
+        + WithTypeArgs2<Either<A, C>, Either<B, D>>
+        + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    D: 'static,
+    X: TypeApp2<TCon, A, B>,
+    Y: TypeApp2<TCon, C, D>,
+{
+    <TCon as ArrowChoice>::split_either(f.into_val(), g.into_val())
+}
+
+// fanin(f, g)
+pub fn fanin<TCon, A, B, C, X, Y>(f: X, g: Y) -> <TCon as WithTypeArgs2<Either<A, B>, C>>::Type
+where
+    TCon: ArrowChoice
+        + WithTypeArgs2<A, C>
+        + WithTypeArgs2<B, C>
+        + WithTypeArgs2<Either<A, B>, Either<C, B>>
+        + WithTypeArgs2<Either<B, C>, Either<C, C>>
+        + WithTypeArgs2<Either<C, B>, Either<C, C>>
+        + WithTypeArgs2<Either<A, B>, Either<C, C>>
+        + WithTypeArgs2<Either<C, C>, C>
+        + WithTypeArgs2<Either<A, B>, C>
+        + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    X: TypeApp2<TCon, A, C>,
+    Y: TypeApp2<TCon, B, C>,
+{
+    <TCon as ArrowChoice>::fanin(f.into_val(), g.into_val())
+}
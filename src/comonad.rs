@@ -0,0 +1,129 @@
+use crate::*;
+use is_type::Is;
+
+// Comonad is the dual of Monad. Instead of putting a value into a context with `lift`, `extract`
+// gets the value at the focus of one. Instead of `bind`, `extend` runs a function at every
+// position of the structure, giving it the whole structure as seen from that position.
+//
+// Contexts often overlap, like the suffixes of a NonEmpty, so building them needs copies of the
+// values. Hence `extract` and `extend` require `Clone`.
+pub trait Comonad: Functor {
+    fn extract<T>(x: &<Self as WithTypeArg<T>>::Type) -> T
+    where
+        Self: WithTypeArg<T>,
+        T: Clone;
+
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&<Self as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone;
+
+    fn duplicate<T>(
+        x: &<Self as WithTypeArg<T>>::Type,
+    ) -> <Self as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        Self: WithTypeArg<T> + WithTypeArg<<Self as WithTypeArg<T>>::Type>,
+        T: Clone,
+        <Self as WithTypeArg<T>>::Type: Clone,
+    {
+        <Self as Comonad>::extend::<T, <Self as WithTypeArg<T>>::Type, _>(|y| y.clone(), x)
+    }
+}
+
+// LinearComonad
+//
+// `lduplicate` is the primitive here, as the contexts it builds are handed over by value.
+pub trait LinearComonad: LinearFunctor {
+    fn lextract<T>(x: <Self as WithTypeArg<T>>::Type) -> T
+    where
+        Self: WithTypeArg<T>;
+
+    fn lduplicate<T>(
+        x: <Self as WithTypeArg<T>>::Type,
+    ) -> <Self as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        Self: WithTypeArg<T> + WithTypeArg<<Self as WithTypeArg<T>>::Type>,
+        T: Clone;
+
+    fn lextend<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut> + WithTypeArg<<Self as WithTypeArg<TIn>>::Type>,
+        F: Fn(<Self as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        <Self as LinearFunctor>::lmap(f, <Self as LinearComonad>::lduplicate::<TIn>(x))
+    }
+}
+
+// extract(x)
+pub fn extract<TCon, T, X>(x: &X) -> T
+where
+    TCon: Comonad + WithTypeArg<T> + ?Sized,
+    T: Clone,
+    X: TypeApp<TCon, T> + ?Sized,
+{
+    <TCon as Comonad>::extract::<T>(x.into_ref())
+}
+
+// extend(f, x)
+pub fn extend<TCon, TIn, TOut, F, X>(f: F, x: &X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: Comonad + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&X) -> TOut,
+    TIn: Clone,
+    X: TypeApp<TCon, TIn>,
+{
+    <TCon as Comonad>::extend::<TIn, TOut, _>(|y| f(Is::from_ref(y)), x.into_ref())
+}
+
+// duplicate(x)
+pub fn duplicate<TCon, T, X>(x: &X) -> <TCon as WithTypeArg<<TCon as WithTypeArg<T>>::Type>>::Type
+where
+    TCon: Comonad + WithTypeArg<T> + WithTypeArg<<TCon as WithTypeArg<T>>::Type> + ?Sized,
+    T: Clone,
+    X: TypeApp<TCon, T> + ?Sized,
+    <TCon as WithTypeArg<T>>::Type: Clone,
+{
+    <TCon as Comonad>::duplicate::<T>(x.into_ref())
+}
+
+// lextract(x)
+pub fn lextract<TCon, T, X>(x: X) -> T
+where
+    TCon: LinearComonad + WithTypeArg<T> + ?Sized,
+    X: TypeApp<TCon, T>,
+{
+    <TCon as LinearComonad>::lextract::<T>(x.into_val())
+}
+
+// lextend(f, x)
+pub fn lextend<TCon, TIn, TOut, F, X>(f: F, x: X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearComonad
+        + WithTypeArg<TIn>
+        + WithTypeArg<TOut>
+        + WithTypeArg<<TCon as WithTypeArg<TIn>>::Type>
+        + ?Sized,
+    F: Fn(X) -> TOut,
+    TIn: Clone,
+    X: TypeApp<TCon, TIn>,
+{
+    <TCon as LinearComonad>::lextend::<TIn, TOut, _>(|y| f(Is::from_val(y)), x.into_val())
+}
+
+// lduplicate(x)
+pub fn lduplicate<TCon, T, X>(x: X) -> <TCon as WithTypeArg<<TCon as WithTypeArg<T>>::Type>>::Type
+where
+    TCon: LinearComonad + WithTypeArg<T> + WithTypeArg<<TCon as WithTypeArg<T>>::Type> + ?Sized,
+    T: Clone,
+    X: TypeApp<TCon, T>,
+{
+    <TCon as LinearComonad>::lduplicate::<T>(x.into_val())
+}
@@ -0,0 +1,147 @@
+use crate::*;
+
+// Some type constructors can only hold certain types, like BTreeSet, which needs its elements
+// to be Ord. These can't implement Functor, as `fmap` has no way to require anything of `TOut`.
+//
+// Instead the type constructor implements `Constrained<T>` for each `T` it can hold, and the
+// traits below require it of every type they use. This is the equivalent of the associated
+// constraint one would use in Haskell.
+//
+// Knowing `TCon: Constrained<T>` doesn't tell the instances that `T: Ord` (or whatever the
+// constraint is), so this also has to provide the operations the instances need that use it,
+// which is building a value from its elements.
+pub trait Constrained<T> {
+    fn from_elements<I>(iter: I) -> <Self as WithTypeArg<T>>::Type
+    where
+        Self: WithTypeArg<T>,
+        I: IntoIterator<Item = T>;
+}
+
+pub trait CFunctor {
+    fn cmap<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn> + Constrained<TOut> + WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&TIn) -> TOut;
+
+    fn lcmap<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn> + Constrained<TOut> + WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(TIn) -> TOut;
+}
+
+// cmap(f, x)
+pub fn cmap<TCon, TIn, TOut, F, X>(f: F, x: &X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: CFunctor
+        + Constrained<TIn>
+        + Constrained<TOut>
+        + WithTypeArg<TIn>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn) -> TOut,
+    X: TypeApp<TCon, TIn> + ?Sized,
+{
+    <TCon as CFunctor>::cmap(f, x.into_ref())
+}
+
+// lcmap(f, x)
+pub fn lcmap<TCon, TIn, TOut, F, X>(f: F, x: X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: CFunctor
+        + Constrained<TIn>
+        + Constrained<TOut>
+        + WithTypeArg<TIn>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(TIn) -> TOut,
+    X: TypeApp<TCon, TIn>,
+{
+    <TCon as CFunctor>::lcmap(f, x.into_val())
+}
+
+pub trait CLift {
+    fn clift<T>(x: T) -> <Self as WithTypeArg<T>>::Type
+    where
+        Self: Constrained<T> + WithTypeArg<T>,
+    {
+        <Self as Constrained<T>>::from_elements(std::iter::once(x))
+    }
+}
+
+// clift::<TCon, _>(x)
+pub fn clift<TCon, T>(x: T) -> <TCon as WithTypeArg<T>>::Type
+where
+    TCon: CLift + Constrained<T> + WithTypeArg<T> + ?Sized,
+{
+    <TCon as CLift>::clift(x)
+}
+
+pub trait CApplicative: CFunctor + CLift {
+    fn clift2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn1>
+            + Constrained<TIn2>
+            + Constrained<TOut>
+            + WithTypeArg<TIn1>
+            + WithTypeArg<TIn2>
+            + WithTypeArg<TOut>,
+        F: Fn(&TIn1, &TIn2) -> TOut;
+}
+
+// clift2(f, x1, x2)
+pub fn clift2<TCon, TIn1, TIn2, TOut, F, X1, X2>(
+    f: F,
+    x1: &X1,
+    x2: &X2,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: CApplicative
+        + Constrained<TIn1>
+        + Constrained<TIn2>
+        + Constrained<TOut>
+        + WithTypeArg<TIn1>
+        + WithTypeArg<TIn2>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn1, &TIn2) -> TOut,
+    X1: TypeApp<TCon, TIn1> + ?Sized,
+    X2: TypeApp<TCon, TIn2> + ?Sized,
+{
+    <TCon as CApplicative>::clift2(f, x1.into_ref(), x2.into_ref())
+}
+
+pub trait CMonad: CApplicative {
+    fn cbind<TIn, TOut, F>(
+        x: &<Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn> + Constrained<TOut> + WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&TIn) -> <Self as WithTypeArg<TOut>>::Type;
+}
+
+// cbind(x, f)
+pub fn cbind<TCon, TIn, TOut, F, X, TResult>(x: &X, f: F) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: CMonad
+        + Constrained<TIn>
+        + Constrained<TOut>
+        + WithTypeArg<TIn>
+        + WithTypeArg<TOut>
+        + ?Sized,
+    F: Fn(&TIn) -> TResult,
+    X: TypeApp<TCon, TIn> + ?Sized,
+    TResult: TypeApp<TCon, TOut>,
+{
+    <TCon as CMonad>::cbind::<TIn, TOut, _>(x.into_ref(), |y| f(y).into_val())
+}
@@ -0,0 +1,123 @@
+use crate::*;
+use std::convert::Infallible;
+
+// A Contravariant functor consumes values rather than producing them, so it maps "backwards":
+// given a way to turn `TOut`s into `TIn`s, a consumer of `TIn`s becomes a consumer of `TOut`s.
+//
+// The instances are newtypes around boxed functions, so the function passed to `contramap` is
+// kept inside the result, and it and the types it consumes have to be `'static`. There are no
+// "by reference" versions, as the boxed functions can't be cloned.
+pub trait Contravariant {
+    fn contramap<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&TOut) -> TIn + 'static,
+        TIn: 'static;
+}
+
+// contramap(f, x)
+pub fn contramap<TCon, TIn, TOut, F, X>(f: F, x: X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: Contravariant + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&TOut) -> TIn + 'static,
+    TIn: 'static,
+    X: TypeApp<TCon, TIn>,
+{
+    <TCon as Contravariant>::contramap(f, x.into_val())
+}
+
+// Divisible is the contravariant version of Applicative. `divide` splits a value into two parts
+// and consumes each with its own consumer, and `conquer` consumes anything, doing nothing.
+pub trait Divisible: Contravariant {
+    fn divide<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        F: Fn(&TOut) -> (TIn1, TIn2) + 'static,
+        TIn1: 'static,
+        TIn2: 'static;
+
+    fn conquer<T>() -> <Self as WithTypeArg<T>>::Type
+    where
+        Self: WithTypeArg<T>;
+}
+
+// divide(f, x1, x2)
+pub fn divide<TCon, TIn1, TIn2, TOut, F, X1, X2>(
+    f: F,
+    x1: X1,
+    x2: X2,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: Divisible + WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&TOut) -> (TIn1, TIn2) + 'static,
+    TIn1: 'static,
+    TIn2: 'static,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+{
+    <TCon as Divisible>::divide(f, x1.into_val(), x2.into_val())
+}
+
+// conquer::<TCon, _>()
+pub fn conquer<TCon, T>() -> <TCon as WithTypeArg<T>>::Type
+where
+    TCon: Divisible + WithTypeArg<T> + ?Sized,
+{
+    <TCon as Divisible>::conquer()
+}
+
+// Decidable is to sums what Divisible is to products. `choose` sends each value to one of two
+// consumers, and `lose` consumes values which can't exist.
+pub trait Decidable: Divisible {
+    fn choose<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <Self as WithTypeArg<TIn1>>::Type,
+        x2: <Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut>,
+        F: Fn(&TOut) -> Either<TIn1, TIn2> + 'static,
+        TIn1: 'static,
+        TIn2: 'static;
+
+    fn lose<T, F>(f: F) -> <Self as WithTypeArg<T>>::Type
+    where
+        Self: WithTypeArg<T> + WithTypeArg<Infallible>,
+        F: Fn(&T) -> Infallible + 'static,
+    {
+        <Self as Contravariant>::contramap(f, <Self as Divisible>::conquer::<Infallible>())
+    }
+}
+
+// choose(f, x1, x2)
+pub fn choose<TCon, TIn1, TIn2, TOut, F, X1, X2>(
+    f: F,
+    x1: X1,
+    x2: X2,
+) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: Decidable + WithTypeArg<TIn1> + WithTypeArg<TIn2> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&TOut) -> Either<TIn1, TIn2> + 'static,
+    TIn1: 'static,
+    TIn2: 'static,
+    X1: TypeApp<TCon, TIn1>,
+    X2: TypeApp<TCon, TIn2>,
+{
+    <TCon as Decidable>::choose(f, x1.into_val(), x2.into_val())
+}
+
+// lose::<TCon, _, _>(f)
+pub fn lose<TCon, T, F>(f: F) -> <TCon as WithTypeArg<T>>::Type
+where
+    TCon: Decidable + WithTypeArg<T> + WithTypeArg<Infallible> + ?Sized,
+    F: Fn(&T) -> Infallible + 'static,
+{
+    <TCon as Decidable>::lose(f)
+}
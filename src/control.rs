@@ -0,0 +1,399 @@
+use crate::*;
+
+// Generic versions of the combinators in Haskell's Control.Monad.
+//
+// The trait bounds on these are painful to derive by hand, so they live here once.
+// As with the rest of the library, the "normal" versions work by reference and
+// the "linear" versions (prefixed with `l`) consume their arguments.
+//
+// Functions which only need applicative structure (e.g. `map_m`) only ask for `Applicative`,
+// so they work on any `Monad` and a few more types besides.
+
+pub(crate) fn snoc<T>(mut xs: Vec<T>, x: T) -> Vec<T> {
+    xs.push(x);
+    xs
+}
+
+// map_m(f, xs)
+pub fn map_m<TCon, TIn, TOut, I, F, TResult>(f: F, xs: I) -> <TCon as WithTypeArg<Vec<TOut>>>::Type
+where
+    TCon: Applicative + WithTypeArg<TOut> + WithTypeArg<Vec<TOut>> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+    TOut: Clone,
+{
+    xs.into_iter().fold(lift::<TCon, _>(Vec::new()), |acc, x| {
+        <TCon as Applicative>::lift2(
+            |ys: &Vec<TOut>, y: &TOut| snoc(ys.clone(), y.clone()),
+            &acc,
+            f(x).into_ref(),
+        )
+    })
+}
+
+// map_m_(f, xs)
+pub fn map_m_<TCon, TIn, TOut, I, F, TResult>(f: F, xs: I) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Applicative + WithTypeArg<TOut> + WithTypeArg<()> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    xs.into_iter().fold(lift::<TCon, _>(()), |acc, x| {
+        <TCon as Applicative>::lift2(|_: &(), _: &TOut| (), &acc, f(x).into_ref())
+    })
+}
+
+// for_m(xs, f)
+pub fn for_m<TCon, TIn, TOut, I, F, TResult>(xs: I, f: F) -> <TCon as WithTypeArg<Vec<TOut>>>::Type
+where
+    TCon: Applicative + WithTypeArg<TOut> + WithTypeArg<Vec<TOut>> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+    TOut: Clone,
+{
+    map_m(f, xs)
+}
+
+// for_m_(xs, f)
+pub fn for_m_<TCon, TIn, TOut, I, F, TResult>(xs: I, f: F) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Applicative + WithTypeArg<TOut> + WithTypeArg<()> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    map_m_(f, xs)
+}
+
+// replicate_m(n, x)
+pub fn replicate_m<TCon, T, X>(n: usize, x: &X) -> <TCon as WithTypeArg<Vec<T>>>::Type
+where
+    TCon: Applicative + WithTypeArg<T> + WithTypeArg<Vec<T>> + ?Sized,
+    X: TypeApp<TCon, T> + ?Sized,
+    T: Clone,
+{
+    (0..n).fold(lift::<TCon, _>(Vec::new()), |acc, _| {
+        <TCon as Applicative>::lift2(
+            |ys: &Vec<T>, y: &T| snoc(ys.clone(), y.clone()),
+            &acc,
+            x.into_ref(),
+        )
+    })
+}
+
+// replicate_m_(n, x)
+pub fn replicate_m_<TCon, T, X>(n: usize, x: &X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Applicative + WithTypeArg<T> + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, T> + ?Sized,
+{
+    (0..n).fold(lift::<TCon, _>(()), |acc, _| {
+        <TCon as Applicative>::lift2(|_: &(), _: &T| (), &acc, x.into_ref())
+    })
+}
+
+// filter_m(p, xs)
+pub fn filter_m<TCon, T, I, F, TResult>(p: F, xs: I) -> <TCon as WithTypeArg<Vec<T>>>::Type
+where
+    TCon: Applicative + WithTypeArg<bool> + WithTypeArg<Vec<T>> + ?Sized,
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> TResult,
+    TResult: TypeApp<TCon, bool>,
+    T: Clone,
+{
+    xs.into_iter().fold(lift::<TCon, _>(Vec::new()), |acc, x| {
+        <TCon as Applicative>::lift2(
+            |ys: &Vec<T>, keep: &bool| {
+                if *keep {
+                    snoc(ys.clone(), x.clone())
+                } else {
+                    ys.clone()
+                }
+            },
+            &acc,
+            p(&x).into_ref(),
+        )
+    })
+}
+
+// fold_m(f, init, xs)
+pub fn fold_m<TCon, TAcc, TIn, I, F, TResult>(
+    f: F,
+    init: TAcc,
+    xs: I,
+) -> <TCon as WithTypeArg<TAcc>>::Type
+where
+    TCon: Monad + WithTypeArg<TAcc> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(&TAcc, &TIn) -> TResult,
+    TResult: TypeApp<TCon, TAcc>,
+{
+    xs.into_iter().fold(lift::<TCon, _>(init), |acc, x| {
+        <TCon as Monad>::bind(&acc, |y: &TAcc| f(y, &x).into_val())
+    })
+}
+
+// zip_with_m(f, xs, ys)
+pub fn zip_with_m<TCon, TIn1, TIn2, TOut, I1, I2, F, TResult>(
+    f: F,
+    xs: I1,
+    ys: I2,
+) -> <TCon as WithTypeArg<Vec<TOut>>>::Type
+where
+    TCon: Applicative + WithTypeArg<TOut> + WithTypeArg<Vec<TOut>> + ?Sized,
+    I1: IntoIterator<Item = TIn1>,
+    I2: IntoIterator<Item = TIn2>,
+    F: Fn(TIn1, TIn2) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+    TOut: Clone,
+{
+    map_m(|(x, y)| f(x, y), xs.into_iter().zip(ys))
+}
+
+// zip_with_m_(f, xs, ys)
+pub fn zip_with_m_<TCon, TIn1, TIn2, TOut, I1, I2, F, TResult>(
+    f: F,
+    xs: I1,
+    ys: I2,
+) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Applicative + WithTypeArg<TOut> + WithTypeArg<()> + ?Sized,
+    I1: IntoIterator<Item = TIn1>,
+    I2: IntoIterator<Item = TIn2>,
+    F: Fn(TIn1, TIn2) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    map_m_(|(x, y)| f(x, y), xs.into_iter().zip(ys))
+}
+
+// when(cond, x)
+pub fn when<TCon, X>(cond: bool, x: &X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Functor + Lift + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, ()> + ?Sized,
+{
+    if cond {
+        <TCon as Functor>::fmap(|_: &()| (), x.into_ref())
+    } else {
+        lift::<TCon, _>(())
+    }
+}
+
+// unless(cond, x)
+pub fn unless<TCon, X>(cond: bool, x: &X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Functor + Lift + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, ()> + ?Sized,
+{
+    when(!cond, x)
+}
+
+// void(x)
+pub fn void<TCon, T, X>(x: &X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Functor + WithTypeArg<T> + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, T> + ?Sized,
+{
+    <TCon as Functor>::fmap(|_: &T| (), x.into_ref())
+}
+
+// kleisli(f, g), which is Haskell's (f >=> g)
+pub fn kleisli<TCon, TIn, TMid, TOut, F, G, TMidResult, TResult>(
+    f: F,
+    g: G,
+) -> impl Fn(&TIn) -> TResult
+where
+    TCon: Monad + WithTypeArg<TMid> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&TIn) -> TMidResult,
+    G: Fn(&TMid) -> TResult,
+    TMidResult: TypeApp<TCon, TMid>,
+    TResult: TypeApp<TCon, TOut>,
+{
+    move |x| bind(&f(x), &g)
+}
+
+// lmap_m(f, xs)
+pub fn lmap_m<TCon, TIn, TOut, I, F, TResult>(f: F, xs: I) -> <TCon as WithTypeArg<Vec<TOut>>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TOut> + WithTypeArg<Vec<TOut>> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    xs.into_iter().fold(lift::<TCon, _>(Vec::new()), |acc, x| {
+        <TCon as LinearApplicative>::llift2(snoc, acc, f(x).into_val())
+    })
+}
+
+// lmap_m_(f, xs)
+pub fn lmap_m_<TCon, TIn, TOut, I, F, TResult>(f: F, xs: I) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TOut> + WithTypeArg<()> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    xs.into_iter().fold(lift::<TCon, _>(()), |acc, x| {
+        <TCon as LinearApplicative>::llift2(|_: (), _: TOut| (), acc, f(x).into_val())
+    })
+}
+
+// lfor_m(xs, f)
+pub fn lfor_m<TCon, TIn, TOut, I, F, TResult>(xs: I, f: F) -> <TCon as WithTypeArg<Vec<TOut>>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TOut> + WithTypeArg<Vec<TOut>> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    lmap_m(f, xs)
+}
+
+// lfor_m_(xs, f)
+pub fn lfor_m_<TCon, TIn, TOut, I, F, TResult>(xs: I, f: F) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TOut> + WithTypeArg<()> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    lmap_m_(f, xs)
+}
+
+// lreplicate_m(n, x)
+pub fn lreplicate_m<TCon, T, X>(n: usize, x: X) -> <TCon as WithTypeArg<Vec<T>>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<T> + WithTypeArg<Vec<T>> + ?Sized,
+    X: TypeApp<TCon, T> + Clone,
+{
+    (0..n).fold(lift::<TCon, _>(Vec::new()), |acc, _| {
+        <TCon as LinearApplicative>::llift2(snoc, acc, x.clone().into_val())
+    })
+}
+
+// lreplicate_m_(n, x)
+pub fn lreplicate_m_<TCon, T, X>(n: usize, x: X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<T> + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, T> + Clone,
+{
+    (0..n).fold(lift::<TCon, _>(()), |acc, _| {
+        <TCon as LinearApplicative>::llift2(|_: (), _: T| (), acc, x.clone().into_val())
+    })
+}
+
+// lfilter_m(p, xs)
+pub fn lfilter_m<TCon, T, I, F, TResult>(p: F, xs: I) -> <TCon as WithTypeArg<Vec<T>>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<bool> + WithTypeArg<Vec<T>> + ?Sized,
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> TResult,
+    TResult: TypeApp<TCon, bool>,
+{
+    xs.into_iter().fold(lift::<TCon, _>(Vec::new()), |acc, x| {
+        let keep = p(&x).into_val();
+        <TCon as LinearApplicative>::llift2(
+            move |ys: Vec<T>, keep: bool| if keep { snoc(ys, x) } else { ys },
+            acc,
+            keep,
+        )
+    })
+}
+
+// lfold_m(f, init, xs)
+pub fn lfold_m<TCon, TAcc, TIn, I, F, TResult>(
+    f: F,
+    init: TAcc,
+    xs: I,
+) -> <TCon as WithTypeArg<TAcc>>::Type
+where
+    TCon: LinearMonad + WithTypeArg<TAcc> + ?Sized,
+    I: IntoIterator<Item = TIn>,
+    F: Fn(TAcc, TIn) -> TResult,
+    TResult: TypeApp<TCon, TAcc>,
+{
+    xs.into_iter().fold(lift::<TCon, _>(init), |acc, x| {
+        <TCon as LinearMonad>::lbind(acc, |y: TAcc| f(y, x).into_val())
+    })
+}
+
+// lzip_with_m(f, xs, ys)
+pub fn lzip_with_m<TCon, TIn1, TIn2, TOut, I1, I2, F, TResult>(
+    f: F,
+    xs: I1,
+    ys: I2,
+) -> <TCon as WithTypeArg<Vec<TOut>>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TOut> + WithTypeArg<Vec<TOut>> + ?Sized,
+    I1: IntoIterator<Item = TIn1>,
+    I2: IntoIterator<Item = TIn2>,
+    F: Fn(TIn1, TIn2) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    lmap_m(|(x, y)| f(x, y), xs.into_iter().zip(ys))
+}
+
+// lzip_with_m_(f, xs, ys)
+pub fn lzip_with_m_<TCon, TIn1, TIn2, TOut, I1, I2, F, TResult>(
+    f: F,
+    xs: I1,
+    ys: I2,
+) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: LinearApplicative + WithTypeArg<TOut> + WithTypeArg<()> + ?Sized,
+    I1: IntoIterator<Item = TIn1>,
+    I2: IntoIterator<Item = TIn2>,
+    F: Fn(TIn1, TIn2) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    lmap_m_(|(x, y)| f(x, y), xs.into_iter().zip(ys))
+}
+
+// lwhen(cond, x)
+pub fn lwhen<TCon, X>(cond: bool, x: X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Lift + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, ()>,
+{
+    if cond {
+        x.into_val()
+    } else {
+        lift::<TCon, _>(())
+    }
+}
+
+// lunless(cond, x)
+pub fn lunless<TCon, X>(cond: bool, x: X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: Lift + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, ()>,
+{
+    lwhen(!cond, x)
+}
+
+// lvoid(x)
+pub fn lvoid<TCon, T, X>(x: X) -> <TCon as WithTypeArg<()>>::Type
+where
+    TCon: LinearFunctor + WithTypeArg<T> + WithTypeArg<()> + ?Sized,
+    X: TypeApp<TCon, T>,
+{
+    <TCon as LinearFunctor>::lmap(|_: T| (), x.into_val())
+}
+
+// lkleisli(f, g), the linear version of (f >=> g)
+pub fn lkleisli<TCon, TIn, TMid, TOut, F, G, TMidResult, TResult>(
+    f: F,
+    g: G,
+) -> impl Fn(TIn) -> TResult
+where
+    TCon: LinearMonad + WithTypeArg<TMid> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(TIn) -> TMidResult,
+    G: Fn(TMid) -> TResult,
+    TMidResult: TypeApp<TCon, TMid>,
+    TResult: TypeApp<TCon, TOut>,
+{
+    move |x| lbind(f(x), &g)
+}
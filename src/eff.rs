@@ -0,0 +1,705 @@
+use crate::{gat, Either, Lift, Monoid, TypeApp, TypeAppParam, WithTypeArg};
+use std::any::Any;
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+pub use crate::lift;
+
+// Extensible effects with the Freer monad, as in Kiselyov and Ishii's "Freer Monads, More
+// Extensible Effects".
+//
+// An `Eff<R, A>` is a program which returns an `A` and may make requests of the effects in its
+// row `R`. The row is a list of effect types written as nested pairs ending in `()`, like
+// `(State<i32>, (Writer<Vec<String>>, ()))`, and a program which is generic in its row says which
+// effects it needs with `Member` bounds. An effect is just a type whose values are requests,
+// usually an enum with a variant per operation, sent with `send`.
+//
+// Handlers give effects their meaning one at a time: `handle` (or `handle_state`, for handlers
+// which keep some state of their own) answers the requests of one effect, and takes it out of the
+// row. Once the row is empty, `run` gets the result. So a program can be given different
+// handlers, e.g. a real one and one with canned answers for tests, in place of a trait passed in
+// to it.
+//
+// Unlike `Free`, no Functor is needed, and the rest of the program after a request is kept in a
+// type aligned queue of continuations, so binds take constant time however they're nested.
+// The queue can't be typed in Rust, so the values going through it are `Box<dyn Any>`. The types
+// are tied together at its ends instead: each request holds a `Reply<T>`, which `send` makes for
+// the `T` the program expects back, and a handler answers by giving `Resume` the reply along with
+// a `T`, so it can't answer with anything else.
+//
+// A program's requests are kept as a sum of its row's effects, so an `Eff<(), A>` can't hold
+// one, and `run` only has to deal with the result.
+//
+// The continuations are kept until a handler answers, so the functions given to `bind` have to
+// be `'static`, which the crate's `Monad::bind` doesn't ask for. So `Eff` isn't a `Monad`, and
+// this module has its own `bind` and `fmap` instead. `mdo!` calls whichever `bind` is in scope,
+// so it works with `Eff` as long as it's this one, e.g. with `use haskell_bits::eff::*` (or
+// `use haskell_bits::eff::bind` alongside `use haskell_bits::*`). Values bound in `mdo!` are
+// references which can't be kept, so copy or clone the ones needed by later steps with `let`.
+// `ret<EffTypeCon<R>> x;` works as usual.
+//
+// Handlers answer through `Resume`, which doesn't run the rest of the program straight away but
+// hands it back to be run by `run`, so long programs don't use up the stack.
+pub struct Eff<R, A>
+where
+    R: Row,
+{
+    inner: Inner<<R as Row>::Request, A>,
+}
+
+// `type_con!` can't add the `R: Row` bound, so this is written out by hand.
+pub struct EffTypeCon<R> {
+    _unused: PhantomData<R>,
+}
+
+impl<R, A> TypeAppParam for Eff<R, A>
+where
+    R: Row,
+{
+    type Param = A;
+}
+
+impl<R, A> TypeApp<EffTypeCon<R>, A> for Eff<R, A> where R: Row {}
+
+impl<R> gat::HigherKinded for EffTypeCon<R>
+where
+    R: Row,
+{
+    type Of<A> = Eff<R, A>;
+}
+
+impl<R> Lift for EffTypeCon<R>
+where
+    R: Row,
+{
+    fn lift<T>(x: T) -> <EffTypeCon<R> as WithTypeArg<T>>::Type {
+        Eff::pure(x)
+    }
+}
+
+// A row of effects, and the requests they make: one of the first effect's, or one of the rest's.
+// The empty row's requests are `Infallible`, so it has none.
+pub trait Row: 'static {
+    type Request: 'static;
+}
+
+impl Row for () {
+    type Request = Infallible;
+}
+
+impl<E, R> Row for (E, R)
+where
+    E: 'static,
+    R: Row,
+{
+    type Request = Either<E, <R as Row>::Request>;
+}
+
+// `Q` is the row's requests.
+enum Inner<Q, A> {
+    Pure(A),
+    Impure(Q, Queue<Q>),
+    Delay(Box<dyn FnOnce() -> Untyped<Q>>),
+}
+
+type Untyped<Q> = Inner<Q, Box<dyn Any>>;
+
+type Arrow<Q> = Rc<dyn Fn(Box<dyn Any>) -> Untyped<Q>>;
+
+// The type aligned queue. It's a tree so appending is constant time, and `view_left` rotates
+// it as it goes, so taking continuations off the front is amortised constant time.
+enum Queue<Q> {
+    Leaf(Arrow<Q>),
+    Node(Rc<Queue<Q>>, Rc<Queue<Q>>),
+}
+
+// Dropping a queue node by node would recurse as deep as the tree, which left nested binds make as
+// deep as the program. So the nodes nothing else shares are taken apart with an explicit stack,
+// leaving an empty leaf in their place.
+impl<Q> Drop for Queue<Q> {
+    fn drop(&mut self) {
+        let mut hole = None;
+        let mut stack = Vec::new();
+        take_unshared(self, &mut hole, &mut stack);
+        while let Some(mut x) = stack.pop() {
+            take_unshared(&mut x, &mut hole, &mut stack);
+        }
+    }
+}
+
+fn take_unshared<Q>(x: &mut Queue<Q>, hole: &mut Option<Rc<Queue<Q>>>, stack: &mut Vec<Queue<Q>>) {
+    if let Queue::Node(l, r) = x {
+        for child in [l, r] {
+            if Rc::get_mut(child).is_some() {
+                let hole = hole.get_or_insert_with(|| {
+                    Rc::new(Queue::Leaf(Rc::new(|_| {
+                        unreachable!("a dropped queue ran")
+                    })))
+                });
+                if let Ok(y) = Rc::try_unwrap(std::mem::replace(child, hole.clone())) {
+                    stack.push(y);
+                }
+            }
+        }
+    }
+}
+
+impl<Q> Clone for Queue<Q> {
+    fn clone(&self) -> Self {
+        match self {
+            Queue::Leaf(k) => Queue::Leaf(k.clone()),
+            Queue::Node(l, r) => Queue::Node(l.clone(), r.clone()),
+        }
+    }
+}
+
+impl<Q> Queue<Q>
+where
+    Q: 'static,
+{
+    fn leaf<F>(f: F) -> Queue<Q>
+    where
+        F: Fn(Box<dyn Any>) -> Untyped<Q> + 'static,
+    {
+        Queue::Leaf(Rc::new(f))
+    }
+
+    fn append(self, other: Queue<Q>) -> Queue<Q> {
+        Queue::Node(Rc::new(self), Rc::new(other))
+    }
+
+    fn view_left(&self) -> (Arrow<Q>, Option<Queue<Q>>) {
+        let mut left = self;
+        let mut rest: Option<Queue<Q>> = None;
+        loop {
+            match left {
+                Queue::Leaf(k) => return (k.clone(), rest),
+                Queue::Node(l, r) => {
+                    rest = Some(match rest {
+                        None => (**r).clone(),
+                        Some(rest) => Queue::Node(r.clone(), Rc::new(rest)),
+                    });
+                    left = l;
+                }
+            }
+        }
+    }
+
+    // Runs the continuations in turn until one makes a request.
+    fn apply(&self, x: Box<dyn Any>) -> Untyped<Q> {
+        let (k, rest) = self.view_left();
+        let mut result = k(x);
+        let mut queue = rest;
+        while let Some(q) = queue {
+            match result {
+                Inner::Pure(y) => {
+                    let (k, rest) = q.view_left();
+                    result = k(y);
+                    queue = rest;
+                }
+                other => return then_queue(other, q),
+            }
+        }
+        result
+    }
+}
+
+// Runs the continuations in `q` after `x`.
+fn then_queue<Q>(x: Untyped<Q>, q: Queue<Q>) -> Untyped<Q>
+where
+    Q: 'static,
+{
+    match x {
+        Inner::Pure(y) => q.apply(y),
+        Inner::Impure(request, k) => Inner::Impure(request, k.append(q)),
+        Inner::Delay(t) => Inner::Delay(Box::new(move || then_queue(t(), q))),
+    }
+}
+
+fn downcast<A: 'static>(x: Box<dyn Any>) -> A {
+    match x.downcast::<A>() {
+        Ok(y) => *y,
+        Err(_) => panic!("an effect handler answered a request with another request's reply"),
+    }
+}
+
+impl<R, A> Eff<R, A>
+where
+    R: Row,
+{
+    fn new(inner: Inner<<R as Row>::Request, A>) -> Self {
+        Eff { inner }
+    }
+
+    pub fn pure(x: A) -> Self {
+        Eff::new(Inner::Pure(x))
+    }
+}
+
+impl<R, A> Eff<R, A>
+where
+    R: Row,
+    A: 'static,
+{
+    fn into_untyped(self) -> Untyped<<R as Row>::Request> {
+        match self.inner {
+            Inner::Pure(x) => Inner::Pure(Box::new(x)),
+            Inner::Impure(request, q) => Inner::Impure(request, q),
+            Inner::Delay(t) => Inner::Delay(t),
+        }
+    }
+
+    fn from_untyped(x: Untyped<<R as Row>::Request>) -> Self {
+        Eff::new(match x {
+            Inner::Pure(y) => Inner::Pure(downcast(y)),
+            Inner::Impure(request, q) => Inner::Impure(request, q),
+            Inner::Delay(t) => Inner::Delay(t),
+        })
+    }
+}
+
+// The list of effects in a row. `I` says where `E` is, and is left for the compiler to work out.
+pub trait Member<E, I>: Row {
+    // The row without `E`.
+    type Rest: Row;
+
+    fn inject(request: E) -> <Self as Row>::Request;
+
+    // The request if it's `E`'s, or else the same request as one of the rest of the row's.
+    fn project(request: <Self as Row>::Request) -> Result<E, <Self::Rest as Row>::Request>;
+}
+
+pub struct Here;
+
+pub struct There<I> {
+    _unused: PhantomData<I>,
+}
+
+impl<E, R> Member<E, Here> for (E, R)
+where
+    E: 'static,
+    R: Row,
+{
+    type Rest = R;
+
+    fn inject(request: E) -> <Self as Row>::Request {
+        Either::Left(request)
+    }
+
+    fn project(request: <Self as Row>::Request) -> Result<E, <R as Row>::Request> {
+        match request {
+            Either::Left(e) => Ok(e),
+            Either::Right(other) => Err(other),
+        }
+    }
+}
+
+impl<E, F, R, I> Member<E, There<I>> for (F, R)
+where
+    F: 'static,
+    R: Member<E, I>,
+{
+    type Rest = (F, <R as Member<E, I>>::Rest);
+
+    fn inject(request: E) -> <Self as Row>::Request {
+        Either::Right(<R as Member<E, I>>::inject(request))
+    }
+
+    fn project(request: <Self as Row>::Request) -> Result<E, <Self::Rest as Row>::Request> {
+        match request {
+            Either::Left(f) => Err(Either::Left(f)),
+            Either::Right(other) => <R as Member<E, I>>::project(other).map_err(Either::Right),
+        }
+    }
+}
+
+// What a request is answered with. A request holds the `Reply` it's to be answered with, which
+// `send` makes, and a handler passes it to `Resume` along with an answer of its type.
+pub struct Reply<T> {
+    _answer: PhantomData<fn(T)>,
+}
+
+impl<T> Clone for Reply<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Reply<T> {}
+
+// Makes a request, which a handler for `E` will answer with a `T`. The request is built from its
+// `Reply`, e.g. `send(State::Get)`.
+pub fn send<R, I, E, T, F>(request: F) -> Eff<R, T>
+where
+    R: Member<E, I>,
+    E: 'static,
+    T: 'static,
+    F: FnOnce(Reply<T>) -> E,
+{
+    let request = request(Reply {
+        _answer: PhantomData,
+    });
+    Eff::new(Inner::Impure(
+        <R as Member<E, I>>::inject(request),
+        Queue::leaf(Inner::Pure),
+    ))
+}
+
+// bind(x, f)
+pub fn bind<R, A, B, F>(x: Eff<R, A>, f: F) -> Eff<R, B>
+where
+    R: Row,
+    A: 'static,
+    B: 'static,
+    F: Fn(&A) -> Eff<R, B> + 'static,
+{
+    match x.inner {
+        Inner::Pure(y) => f(&y),
+        inner => Eff::from_untyped(then_queue(
+            Eff::<R, A>::new(inner).into_untyped(),
+            Queue::leaf(move |y| f(&downcast::<A>(y)).into_untyped()),
+        )),
+    }
+}
+
+// fmap(f, x)
+pub fn fmap<R, A, B, F>(f: F, x: Eff<R, A>) -> Eff<R, B>
+where
+    R: Row,
+    A: 'static,
+    B: 'static,
+    F: Fn(&A) -> B + 'static,
+{
+    bind(x, move |y| Eff::pure(f(y)))
+}
+
+// Gets the result of a program with no effects left.
+pub fn run<A>(x: Eff<(), A>) -> A
+where
+    A: 'static,
+{
+    let mut inner = x.inner;
+    loop {
+        match inner {
+            Inner::Pure(y) => return y,
+            Inner::Delay(t) => inner = Eff::<(), A>::from_untyped(t()).inner,
+            Inner::Impure(request, _) => match request {},
+        }
+    }
+}
+
+type Continue<S, R, B> = dyn Fn(S, Box<dyn Any>) -> Eff<R, B>;
+
+// How a handler answers a request: the rest of the program, handled by the same handler.
+// It can be called any number of times, or not at all.
+pub struct Resume<S, R, B>
+where
+    R: Row,
+{
+    k: Rc<Continue<S, R, B>>,
+}
+
+impl<S, R, B> Clone for Resume<S, R, B>
+where
+    R: Row,
+{
+    fn clone(&self) -> Self {
+        Resume { k: self.k.clone() }
+    }
+}
+
+impl<S, R, B> Resume<S, R, B>
+where
+    S: 'static,
+    R: Row,
+    B: 'static,
+{
+    // Answers the request `reply` came from with `answer`, carrying on with the handler's state
+    // as `s`.
+    pub fn resume_with<T>(&self, s: S, _reply: Reply<T>, answer: T) -> Eff<R, B>
+    where
+        T: 'static,
+    {
+        let k = self.k.clone();
+        Eff::new(Inner::Delay(Box::new(move || {
+            k(s, Box::new(answer)).into_untyped()
+        })))
+    }
+}
+
+impl<R, B> Resume<(), R, B>
+where
+    R: Row,
+    B: 'static,
+{
+    pub fn resume<T>(&self, reply: Reply<T>, answer: T) -> Eff<R, B>
+    where
+        T: 'static,
+    {
+        self.resume_with((), reply, answer)
+    }
+}
+
+type Handled<E, I, R, B> = Eff<<R as Member<E, I>>::Rest, B>;
+
+type OnRequest<E, S, R, B> = dyn Fn(E, S, Resume<S, R, B>) -> Eff<R, B>;
+
+struct Handler<E, S, R, B>
+where
+    R: Row,
+{
+    ret: Box<Continue<S, R, B>>,
+    on: Box<OnRequest<E, S, R, B>>,
+}
+
+type HandlerFor<E, I, S, R, B> = Handler<E, S, <R as Member<E, I>>::Rest, B>;
+
+fn handle_untyped<E, R, I, S, B>(
+    x: Untyped<<R as Row>::Request>,
+    s: S,
+    handler: Rc<HandlerFor<E, I, S, R, B>>,
+) -> Handled<E, I, R, B>
+where
+    R: Member<E, I>,
+    E: 'static,
+    S: Clone + 'static,
+    B: 'static,
+{
+    let mut x = x;
+    loop {
+        match x {
+            Inner::Pure(y) => return (handler.ret)(s, y),
+            Inner::Delay(t) => x = t(),
+            Inner::Impure(request, q) => {
+                let handler2 = handler.clone();
+                let resume = Resume {
+                    k: Rc::new(move |s, answer| {
+                        handle_untyped::<E, R, I, S, B>(q.apply(answer), s, handler2.clone())
+                    }),
+                };
+                return match <R as Member<E, I>>::project(request) {
+                    Ok(e) => (handler.on)(e, s, resume),
+                    // Another effect's request, which goes on up with this handler around the
+                    // rest of the program.
+                    Err(request) => Eff::new(Inner::Impure(
+                        request,
+                        Queue::leaf(move |answer| (resume.k)(s.clone(), answer).into_untyped()),
+                    )),
+                };
+            }
+        }
+    }
+}
+
+// Handles the requests of `E` with `on`, which is given the request and a `Resume` to answer it
+// with. The result of the program goes through `ret`.
+pub fn handle<E, R, I, A, B, Ret, H>(x: Eff<R, A>, ret: Ret, on: H) -> Handled<E, I, R, B>
+where
+    R: Member<E, I>,
+    E: 'static,
+    A: 'static,
+    B: 'static,
+    Ret: Fn(A) -> Handled<E, I, R, B> + 'static,
+    H: Fn(E, Resume<(), <R as Member<E, I>>::Rest, B>) -> Handled<E, I, R, B> + 'static,
+{
+    handle_state(x, (), move |_, y| ret(y), move |e, _, k| on(e, k))
+}
+
+// Like `handle`, but the handler keeps a state, starting at `s`, which it's given along with each
+// request and passes on when it resumes.
+pub fn handle_state<E, R, I, S, A, B, Ret, H>(
+    x: Eff<R, A>,
+    s: S,
+    ret: Ret,
+    on: H,
+) -> Handled<E, I, R, B>
+where
+    R: Member<E, I>,
+    E: 'static,
+    S: Clone + 'static,
+    A: 'static,
+    B: 'static,
+    Ret: Fn(S, A) -> Handled<E, I, R, B> + 'static,
+    H: Fn(E, S, Resume<S, <R as Member<E, I>>::Rest, B>) -> Handled<E, I, R, B> + 'static,
+{
+    let handler = Handler {
+        ret: Box::new(move |s, y| ret(s, downcast::<A>(y))),
+        on: Box::new(on),
+    };
+    handle_untyped::<E, R, I, S, B>(x.into_untyped(), s, Rc::new(handler))
+}
+
+// A state which can be read and replaced.
+pub enum State<S> {
+    Get(Reply<S>),
+    Put(S, Reply<()>),
+}
+
+// get()
+pub fn get<R, I, S>() -> Eff<R, S>
+where
+    R: Member<State<S>, I>,
+    S: 'static,
+{
+    send(State::<S>::Get)
+}
+
+// put(s)
+pub fn put<R, I, S>(s: S) -> Eff<R, ()>
+where
+    R: Member<State<S>, I>,
+    S: 'static,
+{
+    send(|reply| State::Put(s, reply))
+}
+
+// modify(f)
+pub fn modify<R, I, S, F>(f: F) -> Eff<R, ()>
+where
+    R: Member<State<S>, I> + 'static,
+    S: 'static,
+    F: Fn(&S) -> S + 'static,
+{
+    bind(get::<R, I, S>(), move |s| put::<R, I, S>(f(s)))
+}
+
+// Handles `State<S>`, starting with `s`, and returns the final state along with the result.
+pub fn run_state<R, I, S, A>(x: Eff<R, A>, s: S) -> Handled<State<S>, I, R, (A, S)>
+where
+    R: Member<State<S>, I>,
+    S: Clone + 'static,
+    A: 'static,
+{
+    handle_state(
+        x,
+        s,
+        |s, y| Eff::pure((y, s)),
+        |request, s, k| match request {
+            State::Get(reply) => k.resume_with(s.clone(), reply, s),
+            State::Put(s, reply) => k.resume_with(s, reply, ()),
+        },
+    )
+}
+
+// An environment which can be read. Its one request is asking for it, with `ask`.
+pub struct Reader<E>(pub Reply<E>);
+
+// ask()
+pub fn ask<R, I, E>() -> Eff<R, E>
+where
+    R: Member<Reader<E>, I>,
+    E: 'static,
+{
+    send(Reader::<E>)
+}
+
+// Handles `Reader<E>` by answering with `e`.
+pub fn run_reader<R, I, E, A>(x: Eff<R, A>, e: E) -> Handled<Reader<E>, I, R, A>
+where
+    R: Member<Reader<E>, I>,
+    E: Clone + 'static,
+    A: 'static,
+{
+    handle(x, Eff::pure, move |Reader(reply), k| {
+        k.resume(reply, e.clone())
+    })
+}
+
+// Output which is collected with a Monoid. Its one request is adding to it, with `tell`.
+pub struct Writer<W>(pub W, pub Reply<()>);
+
+// tell(w)
+pub fn tell<R, I, W>(w: W) -> Eff<R, ()>
+where
+    R: Member<Writer<W>, I>,
+    W: 'static,
+{
+    send(|reply| Writer(w, reply))
+}
+
+// Handles `Writer<W>`, and returns all the output along with the result.
+pub fn run_writer<R, I, W, A>(x: Eff<R, A>) -> Handled<Writer<W>, I, R, (A, W)>
+where
+    R: Member<Writer<W>, I>,
+    W: Monoid + Clone + 'static,
+    A: 'static,
+{
+    handle_state(
+        x,
+        W::default(),
+        |w, y| Eff::pure((y, w)),
+        |Writer(w2, reply), w: W, k| k.resume_with(w.semigroup_op(w2), reply, ()),
+    )
+}
+
+// Failing with an error, with `throw`. A request is never answered, so it doesn't hold a `Reply`.
+pub struct Error<E>(pub E);
+
+// throw(e)
+pub fn throw<R, I, E, A>(e: E) -> Eff<R, A>
+where
+    R: Member<Error<E>, I>,
+    E: 'static,
+    A: 'static,
+{
+    send(|_: Reply<A>| Error(e))
+}
+
+// Handles `Error<E>`, stopping the program at the first error.
+pub fn run_error<R, I, E, A>(x: Eff<R, A>) -> Handled<Error<E>, I, R, Result<A, E>>
+where
+    R: Member<Error<E>, I>,
+    E: 'static,
+    A: 'static,
+{
+    handle(x, |y| Eff::pure(Ok(y)), |Error(e), _| Eff::pure(Err(e)))
+}
+
+// Nondeterminism: a request is answered with each index below the number it holds in turn, so
+// the rest of the program runs once for each. Use `choose` and `guard` rather than sending it.
+pub struct NonDet(pub usize, pub Reply<usize>);
+
+// choose(xs)
+pub fn choose<R, I, A>(xs: Vec<A>) -> Eff<R, A>
+where
+    R: Member<NonDet, I> + 'static,
+    A: Clone + 'static,
+{
+    let n = xs.len();
+    bind(send(|reply| NonDet(n, reply)), move |i: &usize| {
+        Eff::pure(xs[*i].clone())
+    })
+}
+
+// guard(b)
+pub fn guard<R, I>(b: bool) -> Eff<R, ()>
+where
+    R: Member<NonDet, I> + 'static,
+{
+    choose(if b { vec![()] } else { vec![] })
+}
+
+// Handles `NonDet`, and returns the results of all the ways the program can go, in order.
+pub fn run_non_det<R, I, A>(x: Eff<R, A>) -> Handled<NonDet, I, R, Vec<A>>
+where
+    R: Member<NonDet, I>,
+    A: Clone + 'static,
+{
+    handle(
+        x,
+        |y| Eff::pure(vec![y]),
+        |NonDet(n, reply), k| {
+            (0..n).fold(Eff::pure(vec![]), |acc, i| {
+                let k = k.clone();
+                bind(acc, move |xs: &Vec<A>| {
+                    let xs = xs.clone();
+                    fmap(
+                        move |ys: &Vec<A>| xs.iter().chain(ys).cloned().collect(),
+                        k.resume(reply, i),
+                    )
+                })
+            })
+        },
+    )
+}
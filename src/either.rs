@@ -0,0 +1,28 @@
+// A value of one of two types. Result could do this job, but its variants suggest success and
+// failure, whereas here neither side is special.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    pub fn either<T, F, G>(self, f: F, g: G) -> T
+    where
+        F: FnOnce(L) -> T,
+        G: FnOnce(R) -> T,
+    {
+        match self {
+            Either::Left(x) => f(x),
+            Either::Right(x) => g(x),
+        }
+    }
+
+    // Swaps the sides
+    pub fn mirror(self) -> Either<R, L> {
+        match self {
+            Either::Left(x) => Either::Right(x),
+            Either::Right(x) => Either::Left(x),
+        }
+    }
+}
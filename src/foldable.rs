@@ -31,3 +31,92 @@ where
 {
     <TCon as Foldable>::foldr(f, init, x.into_ref())
 }
+
+// Folds over type constructors which always hold at least one value, so don't need an initial
+// value. `fold1` is a right fold, like `lfoldr`.
+pub trait Foldable1: Foldable {
+    fn fold1<T, F>(f: F, x: <Self as WithTypeArg<T>>::Type) -> T
+    where
+        F: Fn(T, T) -> T,
+        Self: WithTypeArg<T>;
+
+    fn head<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T
+    where
+        Self: WithTypeArg<T>;
+
+    fn last<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T
+    where
+        Self: WithTypeArg<T>;
+
+    fn maximum1<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T
+    where
+        T: Ord,
+        Self: WithTypeArg<T>;
+
+    fn minimum1<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T
+    where
+        T: Ord,
+        Self: WithTypeArg<T>;
+}
+
+// fold1(f, x)
+pub fn fold1<TCon, T, F, X>(f: F, x: X) -> T
+where
+    F: Fn(T, T) -> T,
+    TCon: Foldable1 + WithTypeArg<T>,
+    X: TypeApp<TCon, T>,
+{
+    <TCon as Foldable1>::fold1(f, x.into_val())
+}
+
+// sconcat(x)
+pub fn sconcat<TCon, T, X>(x: X) -> T
+where
+    T: Semigroup,
+    TCon: Foldable1 + WithTypeArg<T>,
+    X: TypeApp<TCon, T>,
+{
+    <TCon as Foldable1>::fold1(T::semigroup_op, x.into_val())
+}
+
+// head(x)
+pub fn head<'a, TCon, T, X>(x: &'a X) -> &'a T
+where
+    <TCon as WithTypeArg<T>>::Type: 'a,
+    TCon: Foldable1 + WithTypeArg<T>,
+    X: TypeApp<TCon, T> + ?Sized,
+{
+    <TCon as Foldable1>::head(x.into_ref())
+}
+
+// last(x)
+pub fn last<'a, TCon, T, X>(x: &'a X) -> &'a T
+where
+    <TCon as WithTypeArg<T>>::Type: 'a,
+    TCon: Foldable1 + WithTypeArg<T>,
+    X: TypeApp<TCon, T> + ?Sized,
+{
+    <TCon as Foldable1>::last(x.into_ref())
+}
+
+// maximum1(x)
+pub fn maximum1<'a, TCon, T, X>(x: &'a X) -> &'a T
+where
+    <TCon as WithTypeArg<T>>::Type: 'a,
+    T: Ord,
+    TCon: Foldable1 + WithTypeArg<T>,
+    X: TypeApp<TCon, T> + ?Sized,
+{
+    <TCon as Foldable1>::maximum1(x.into_ref())
+}
+
+// minimum1(x)
+pub fn minimum1<'a, TCon, T, X>(x: &'a X) -> &'a T
+where
+    <TCon as WithTypeArg<T>>::Type: 'a,
+    T: Ord,
+    TCon: Foldable1 + WithTypeArg<T>,
+    X: TypeApp<TCon, T> + ?Sized,
+{
+    <TCon as Foldable1>::minimum1(x.into_ref())
+}
@@ -44,6 +44,125 @@ pub trait Functor: LinearFunctor {
     }
 }
 
+// Functor requires LinearFunctor, which shared pointers like Rc and Arc can't implement, as
+// there's no way to move the value out of one which isn't unique. They can still map by
+// reference though, so the borrowing functions (`fmap`, `lift2`, `bind` and `mdo!`) take this
+// trait, and `SharedApplicative` and `SharedMonad`, which Rc and Arc implement directly.
+//
+// Every Functor is also a SharedFunctor.
+pub trait SharedFunctor {
+    fn fmap_shared<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&TIn) -> TOut;
+}
+
+impl<TCon> SharedFunctor for TCon
+where
+    TCon: Functor + ?Sized,
+{
+    fn fmap_shared<TIn, TOut, F>(
+        f: F,
+        x: &<TCon as WithTypeArg<TIn>>::Type,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&TIn) -> TOut,
+    {
+        <TCon as Functor>::fmap(f, x)
+    }
+}
+
+// LinearFunctor can't be implemented by shared pointers like Rc and Arc, as there's no way to
+// move the value out of one which isn't unique.
+//
+// This version of lmap requires the value to be Clone, so they can move it out when they hold
+// the only reference, and clone it otherwise.
+//
+// Every LinearFunctor is also a LinearFunctorClone.
+pub trait LinearFunctorClone {
+    fn lmap_clone<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(TIn) -> TOut,
+        TIn: Clone;
+}
+
+impl<TCon> LinearFunctorClone for TCon
+where
+    TCon: LinearFunctor + ?Sized,
+{
+    fn lmap_clone<TIn, TOut, F>(
+        f: F,
+        x: <TCon as WithTypeArg<TIn>>::Type,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(TIn) -> TOut,
+    {
+        <TCon as LinearFunctor>::lmap(f, x)
+    }
+}
+
+// lmap_clone(f, x)
+pub fn lmap_clone<TCon, TIn, TOut, F, X>(f: F, x: X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: LinearFunctorClone + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(TIn) -> TOut,
+    TIn: Clone,
+    X: TypeApp<TCon, TIn>,
+{
+    <TCon as LinearFunctorClone>::lmap_clone(f, x.into_val())
+}
+
+// Functors where each value has a key, like the maps, which can be passed to the function
+// along with the value.
+pub trait FunctorWithKey: Functor {
+    type Key;
+
+    fn fmap_with_key<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&Self::Key, &TIn) -> TOut;
+
+    fn lmap_with_key<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&Self::Key, TIn) -> TOut;
+}
+
+// fmap_with_key(f, x)
+pub fn fmap_with_key<TCon, TIn, TOut, F, X>(f: F, x: &X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: FunctorWithKey + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&<TCon as FunctorWithKey>::Key, &TIn) -> TOut,
+    X: TypeApp<TCon, TIn> + ?Sized,
+{
+    <TCon as FunctorWithKey>::fmap_with_key(f, x.into_ref())
+}
+
+// lmap_with_key(f, x)
+pub fn lmap_with_key<TCon, TIn, TOut, F, X>(f: F, x: X) -> <TCon as WithTypeArg<TOut>>::Type
+where
+    TCon: FunctorWithKey + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    F: Fn(&<TCon as FunctorWithKey>::Key, TIn) -> TOut,
+    X: TypeApp<TCon, TIn>,
+{
+    <TCon as FunctorWithKey>::lmap_with_key(f, x.into_val())
+}
+
 // Call this for lmap(f, x) syntax
 pub fn lmap<TCon, TIn, TOut, F, X>(
     f: F,
@@ -76,11 +195,11 @@ pub fn fmap<TCon, TIn, TOut, F, X>(
     x: &X,
 ) -> <TCon as WithTypeArg<TOut>>::Type
 where
-    TCon: Functor + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    TCon: SharedFunctor + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
     X: TypeApp<TCon, TIn> + ?Sized,
     F: Fn(&TIn) -> TOut,
 {
-    TCon::fmap(f, x.into_ref())
+    <TCon as SharedFunctor>::fmap_shared(f, x.into_ref())
 }
 
 // And for fmapconst(e, x)
@@ -90,7 +209,7 @@ pub fn fmapconst<TCon, TIn, TOut, X>(
 ) -> <TCon as WithTypeArg<TOut>>::Type
 where
     TCon: Functor + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
-    TOut: Clone + ?Sized,
+    TOut: Clone,
     X: TypeApp<TCon, TIn>
 {
     TCon::fmapconst::<TIn, TOut>(e, x.into_ref())
@@ -0,0 +1,266 @@
+use crate::*;
+
+// An alternative interface to the traits in this library using generic associated types (GATs),
+// which didn't exist when `WithTypeArg` was written.
+//
+// With `WithTypeArg` a generic function needs a bound for every type it applies the type
+// constructor to, e.g. `TCon: Functor + WithTypeArg<TIn> + WithTypeArg<TMid> + WithTypeArg<TOut>`.
+// Here `TCon::Of<T>` works for any `T`, so the same function only needs `TCon: gat::Functor`:
+//
+// fn double<TCon: gat::Functor>(x: &TCon::Of<u32>) -> TCon::Of<u32> {
+//     TCon::fmap(|y: &u32| y * 2, x)
+// }
+//
+// The two interfaces are bridged in both directions:
+//
+// * every `HigherKinded` type constructor gets `WithTypeArg<T>` for all `T`, with
+//   `<TCon as WithTypeArg<T>>::Type` being `TCon::Of<T>`, and
+// * every type constructor implementing the existing traits gets the traits in this module.
+//
+// So there's nothing extra to implement, the instances are written against the existing traits
+// as usual. `type_con!` (and so `#[derive(TypeCon)]`) implements `HigherKinded` rather than
+// `WithTypeArg` directly, so all the type constructors in this library work with both.
+//
+// The first direction is more than a convenience: `crate::LinearTraversable::sequence` needs an
+// applicative it can apply to the partial results of any structure, which is what a
+// `HigherKinded` applicative gives it. A separate `WithTypeArg` impl per type constructor would
+// instead need a bound for every intermediate type, which a generic `sequence` can't name.
+//
+// The downside is that type inference doesn't work through `TCon::Of<T>`, so there are no free
+// functions here, and you'll need to call the methods on the type constructor, like `TCon::fmap`.
+pub trait HigherKinded {
+    type Of<T>: TypeApp<Self, T>;
+}
+
+impl<TCon, T> WithTypeArg<T> for TCon
+where
+    TCon: HigherKinded + ?Sized,
+{
+    type Type = <TCon as HigherKinded>::Of<T>;
+}
+
+pub trait LinearFunctor: HigherKinded {
+    fn lmap<TIn, TOut, F>(f: F, x: Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        F: Fn(TIn) -> TOut;
+}
+
+impl<TCon> LinearFunctor for TCon
+where
+    TCon: HigherKinded + crate::LinearFunctor + ?Sized,
+{
+    fn lmap<TIn, TOut, F>(f: F, x: Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        <TCon as crate::LinearFunctor>::lmap(f, x)
+    }
+}
+
+pub trait Functor: LinearFunctor {
+    fn fmap<TIn, TOut, F>(f: F, x: &Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        F: Fn(&TIn) -> TOut;
+}
+
+impl<TCon> Functor for TCon
+where
+    TCon: HigherKinded + crate::Functor + ?Sized,
+{
+    fn fmap<TIn, TOut, F>(f: F, x: &Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        <TCon as crate::Functor>::fmap(f, x)
+    }
+}
+
+pub trait LinearApplicative: LinearFunctor {
+    fn lift<T>(x: T) -> Self::Of<T>;
+
+    fn lap<TIn, TOut, TFunc>(f: Self::Of<TFunc>, x: Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        TFunc: FnOnce(TIn) -> TOut;
+
+    fn llift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: Self::Of<TIn1>,
+        x2: Self::Of<TIn2>,
+    ) -> Self::Of<TOut>
+    where
+        TFunc: FnOnce(TIn1, TIn2) -> TOut;
+}
+
+impl<TCon> LinearApplicative for TCon
+where
+    TCon: HigherKinded + crate::LinearFunctor + crate::LinearApplicative + ?Sized,
+{
+    fn lift<T>(x: T) -> Self::Of<T> {
+        <TCon as Lift>::lift(x)
+    }
+
+    fn lap<TIn, TOut, TFunc>(f: Self::Of<TFunc>, x: Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        TFunc: FnOnce(TIn) -> TOut,
+    {
+        <TCon as crate::LinearApplicative>::lap(f, x)
+    }
+
+    fn llift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: Self::Of<TIn1>,
+        x2: Self::Of<TIn2>,
+    ) -> Self::Of<TOut>
+    where
+        TFunc: FnOnce(TIn1, TIn2) -> TOut,
+    {
+        <TCon as crate::LinearApplicative>::llift2(f, x1, x2)
+    }
+}
+
+pub trait Applicative: Functor {
+    fn pure<T>(x: T) -> Self::Of<T>;
+
+    fn ap<TIn, TOut, TFunc>(f: &Self::Of<TFunc>, x: &Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        TFunc: Fn(&TIn) -> TOut;
+
+    fn lift2<TIn1, TIn2, TOut, F>(f: F, x1: &Self::Of<TIn1>, x2: &Self::Of<TIn2>) -> Self::Of<TOut>
+    where
+        F: Fn(&TIn1, &TIn2) -> TOut;
+}
+
+impl<TCon> Applicative for TCon
+where
+    TCon: HigherKinded + crate::Applicative + ?Sized,
+{
+    fn pure<T>(x: T) -> Self::Of<T> {
+        <TCon as Lift>::lift(x)
+    }
+
+    fn ap<TIn, TOut, TFunc>(f: &Self::Of<TFunc>, x: &Self::Of<TIn>) -> Self::Of<TOut>
+    where
+        TFunc: Fn(&TIn) -> TOut,
+    {
+        <TCon as crate::Applicative>::ap(f, x)
+    }
+
+    fn lift2<TIn1, TIn2, TOut, F>(f: F, x1: &Self::Of<TIn1>, x2: &Self::Of<TIn2>) -> Self::Of<TOut>
+    where
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        <TCon as crate::Applicative>::lift2(f, x1, x2)
+    }
+}
+
+pub trait LinearMonad: LinearApplicative {
+    fn lbind<TIn, TOut, F>(x: Self::Of<TIn>, f: F) -> Self::Of<TOut>
+    where
+        F: FnOnce(TIn) -> Self::Of<TOut>;
+}
+
+impl<TCon> LinearMonad for TCon
+where
+    TCon: HigherKinded + crate::LinearFunctor + crate::LinearMonad + ?Sized,
+{
+    fn lbind<TIn, TOut, F>(x: Self::Of<TIn>, f: F) -> Self::Of<TOut>
+    where
+        F: FnOnce(TIn) -> Self::Of<TOut>,
+    {
+        <TCon as crate::LinearMonad>::lbind(x, f)
+    }
+}
+
+pub trait Monad: Applicative {
+    fn bind<TIn, TOut, F>(x: &Self::Of<TIn>, f: F) -> Self::Of<TOut>
+    where
+        F: Fn(&TIn) -> Self::Of<TOut>;
+}
+
+impl<TCon> Monad for TCon
+where
+    TCon: HigherKinded + crate::Monad + ?Sized,
+{
+    fn bind<TIn, TOut, F>(x: &Self::Of<TIn>, f: F) -> Self::Of<TOut>
+    where
+        F: Fn(&TIn) -> Self::Of<TOut>,
+    {
+        <TCon as crate::Monad>::bind(x, f)
+    }
+}
+
+pub trait LinearFoldable: HigherKinded {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: Self::Of<TIn>) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut;
+}
+
+impl<TCon> LinearFoldable for TCon
+where
+    TCon: HigherKinded + crate::LinearFoldable + ?Sized,
+{
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: Self::Of<TIn>) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        <TCon as crate::LinearFoldable>::lfoldr(f, init, x)
+    }
+}
+
+pub trait Foldable: HigherKinded {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &Self::Of<TIn>) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut;
+}
+
+impl<TCon> Foldable for TCon
+where
+    TCon: HigherKinded + crate::Foldable + ?Sized,
+{
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &Self::Of<TIn>) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        <TCon as crate::Foldable>::foldr(f, init, x)
+    }
+}
+
+// `sequence` and `traverse` go to the instances of `crate::LinearTraversable`, which need the
+// applicative to implement `crate::LinearApplicative` too. Every `LinearApplicative` here does, via
+// the impl above, so this doesn't rule any out.
+pub trait LinearTraversable: LinearFunctor + LinearFoldable {
+    fn sequence<TApplicative, T>(x: Self::Of<TApplicative::Of<T>>) -> TApplicative::Of<Self::Of<T>>
+    where
+        TApplicative: LinearApplicative + crate::LinearApplicative;
+
+    fn traverse<TApplicative, TIn, TOut, F>(
+        f: F,
+        x: Self::Of<TIn>,
+    ) -> TApplicative::Of<Self::Of<TOut>>
+    where
+        TApplicative: LinearApplicative + crate::LinearApplicative,
+        F: Fn(TIn) -> TApplicative::Of<TOut>;
+}
+
+impl<TCon> LinearTraversable for TCon
+where
+    TCon: HigherKinded + crate::LinearTraversable + ?Sized,
+{
+    fn sequence<TApplicative, T>(x: Self::Of<TApplicative::Of<T>>) -> TApplicative::Of<Self::Of<T>>
+    where
+        TApplicative: LinearApplicative + crate::LinearApplicative,
+    {
+        <TCon as crate::LinearTraversable>::sequence::<TApplicative, T>(x)
+    }
+
+    fn traverse<TApplicative, TIn, TOut, F>(
+        f: F,
+        x: Self::Of<TIn>,
+    ) -> TApplicative::Of<Self::Of<TOut>>
+    where
+        TApplicative: LinearApplicative + crate::LinearApplicative,
+        F: Fn(TIn) -> TApplicative::Of<TOut>,
+    {
+        <TCon as crate::LinearTraversable>::traverse::<TApplicative, TIn, TOut, F>(f, x)
+    }
+}
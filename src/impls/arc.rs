@@ -0,0 +1,76 @@
+use crate::*;
+use std::sync::Arc;
+
+// Arc can't implement LinearFunctor, as the value can't be moved out while it's shared, and so
+// it can't implement Functor, Applicative or Monad, which build on it. It implements their
+// borrowing halves, SharedFunctor, SharedApplicative and SharedMonad, so `fmap`, `lift2`, `bind`
+// and `mdo!` still work. And there's LinearFunctorClone, which moves the value out if this is
+// the only reference, and clones it otherwise.
+type_con!(pub struct TypeCon for<T> Arc<T>);
+
+impl SharedFunctor for TypeCon {
+    fn fmap_shared<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        Arc::new(f(x))
+    }
+}
+
+impl LinearFunctorClone for TypeCon {
+    fn lmap_clone<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+        TIn: Clone,
+    {
+        Arc::new(f(
+            Arc::try_unwrap(x).unwrap_or_else(|shared| (*shared).clone())
+        ))
+    }
+}
+
+impl Lift for TypeCon {
+    fn lift<T>(x: T) -> <TypeCon as WithTypeArg<T>>::Type {
+        Arc::new(x)
+    }
+}
+
+impl SharedApplicative for TypeCon {
+    fn lift2_shared<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        Arc::new(f(x1, x2))
+    }
+}
+
+impl SharedMonad for TypeCon {
+    fn bind_shared<TIn, TOut, F>(
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        f(x)
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        f(x, init)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::*;
+
+// `type_con!` only handles type parameters, so this is written out by hand.
+//
+// None of these instances allocate.
+pub struct TypeCon<const N: usize>;
+
+impl<T, const N: usize> TypeAppParam for [T; N] {
+    type Param = T;
+}
+
+impl<T, const N: usize> TypeApp<TypeCon<N>, T> for [T; N] {}
+
+impl<const N: usize> gat::HigherKinded for TypeCon<N> {
+    type Of<T> = [T; N];
+}
+
+impl<const N: usize> Functor for TypeCon<N> {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        x.each_ref().map(f)
+    }
+}
+
+impl<const N: usize> LinearFunctor for TypeCon<N> {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        x.map(f)
+    }
+}
+
+// Arrays can't be Applicative, as `lift` would need `N` copies of its argument.
+// But they can be combined index by index.
+impl<const N: usize> Apply for TypeCon<N> {
+    fn lift_f2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        std::array::from_fn(|i| f(&x1[i], &x2[i]))
+    }
+}
+
+impl<const N: usize> LinearFoldable for TypeCon<N> {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        IntoIterator::into_iter(x)
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<const N: usize> Foldable for TypeCon<N> {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.iter().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+// The results are put into an array of `Option`s as the effects are combined, as there's no
+// way to build an array a piece at a time otherwise. By the end each slot has been filled once.
+impl<const N: usize> LinearTraversable for TypeCon<N> {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        let slots = IntoIterator::into_iter(x).enumerate().fold(
+            <TApplicative as Lift>::lift(std::array::from_fn::<Option<T>, N, _>(|_| None)),
+            |acc, (i, y)| {
+                <TApplicative as LinearApplicative>::llift2(
+                    move |mut slots: [Option<T>; N], y: T| {
+                        slots[i] = Some(y);
+                        slots
+                    },
+                    acc,
+                    y,
+                )
+            },
+        );
+        <TApplicative as LinearApplicative>::lap(
+            <TApplicative as Lift>::lift(|slots: [Option<T>; N]| slots.map(Option::unwrap)),
+            slots,
+        )
+    }
+}
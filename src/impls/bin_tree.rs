@@ -0,0 +1,325 @@
+use crate::*;
+
+// A binary tree, with values at the nodes and empty leaves.
+//
+// Its Foldable and Traversable instances visit values in order, i.e. left subtree, node, right
+// subtree, which gives the values of a search tree sorted. The `_preorder` and `_postorder`
+// folds and traversals give the other orders.
+//
+// There's no Applicative or Monad. Substituting a tree for each value has to graft the value's
+// subtrees somewhere onto the new tree, and when that tree is empty they have to be joined some
+// other way, which no choice makes associative.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BinTree<T> {
+    #[default]
+    Leaf,
+    Node(Box<BinTree<T>>, T, Box<BinTree<T>>),
+}
+
+impl<T> BinTree<T> {
+    pub fn node(left: BinTree<T>, value: T, right: BinTree<T>) -> Self {
+        BinTree::Node(Box::new(left), value, Box::new(right))
+    }
+
+    pub fn singleton(value: T) -> Self {
+        BinTree::node(BinTree::Leaf, value, BinTree::Leaf)
+    }
+
+    // The iterators are lazy, keeping a stack as deep as the tree.
+    pub fn preorder(&self) -> impl Iterator<Item = &T> {
+        Iter::new(self, Order::Pre)
+    }
+
+    pub fn inorder(&self) -> impl Iterator<Item = &T> {
+        Iter::new(self, Order::In)
+    }
+
+    pub fn postorder(&self) -> impl Iterator<Item = &T> {
+        Iter::new(self, Order::Post)
+    }
+
+    // The folds and traversals in the orders the instances don't use
+    pub fn foldr_preorder<U, F>(&self, f: F, init: U) -> U
+    where
+        F: Fn(&T, U) -> U,
+    {
+        foldr_refs(Order::Pre, &f, self, init)
+    }
+
+    pub fn foldr_postorder<U, F>(&self, f: F, init: U) -> U
+    where
+        F: Fn(&T, U) -> U,
+    {
+        foldr_refs(Order::Post, &f, self, init)
+    }
+
+    pub fn lfoldr_preorder<U, F>(self, f: F, init: U) -> U
+    where
+        F: Fn(T, U) -> U,
+    {
+        foldr_values(Order::Pre, &f, self, init)
+    }
+
+    pub fn lfoldr_postorder<U, F>(self, f: F, init: U) -> U
+    where
+        F: Fn(T, U) -> U,
+    {
+        foldr_values(Order::Post, &f, self, init)
+    }
+
+    pub fn traverse_preorder<TApplicative, TOut, F>(
+        self,
+        f: F,
+    ) -> <TApplicative as WithTypeArg<BinTree<TOut>>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+        F: Fn(T) -> <TApplicative as WithTypeArg<TOut>>::Type,
+    {
+        sequence_in::<TApplicative, TOut>(Order::Pre, <TypeCon as LinearFunctor>::lmap(f, self))
+    }
+
+    pub fn traverse_postorder<TApplicative, TOut, F>(
+        self,
+        f: F,
+    ) -> <TApplicative as WithTypeArg<BinTree<TOut>>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+        F: Fn(T) -> <TApplicative as WithTypeArg<TOut>>::Type,
+    {
+        sequence_in::<TApplicative, TOut>(Order::Post, <TypeCon as LinearFunctor>::lmap(f, self))
+    }
+
+    // The values in order
+    pub fn flatten(self) -> Vec<T> {
+        fn go<T>(x: BinTree<T>, result: &mut Vec<T>) {
+            if let BinTree::Node(left, value, right) = x {
+                go(*left, result);
+                result.push(value);
+                go(*right, result);
+            }
+        }
+        let mut result = Vec::new();
+        go(self, &mut result);
+        result
+    }
+}
+
+// Builds a tree from a seed, where `f` gives None for a leaf, or the value of a node and the seeds
+// of its subtrees.
+pub fn unfold_bin_tree<S, T, F>(f: F, seed: S) -> BinTree<T>
+where
+    F: Fn(S) -> Option<(S, T, S)>,
+{
+    fn go<S, T>(f: &impl Fn(S) -> Option<(S, T, S)>, seed: S) -> BinTree<T> {
+        match f(seed) {
+            Some((left, value, right)) => BinTree::node(go(f, left), value, go(f, right)),
+            None => BinTree::Leaf,
+        }
+    }
+    go(&f, seed)
+}
+
+#[derive(Clone, Copy)]
+enum Order {
+    Pre,
+    In,
+    Post,
+}
+
+enum Step<'a, T> {
+    Visit(&'a BinTree<T>),
+    Yield(&'a T),
+}
+
+struct Iter<'a, T> {
+    order: Order,
+    stack: Vec<Step<'a, T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn new(x: &'a BinTree<T>, order: Order) -> Self {
+        Iter {
+            order,
+            stack: vec![Step::Visit(x)],
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match self.stack.pop()? {
+                Step::Yield(value) => return Some(value),
+                Step::Visit(BinTree::Leaf) => {}
+                // Pushed last first, so the next step is on top
+                Step::Visit(BinTree::Node(left, value, right)) => {
+                    self.stack.extend(match self.order {
+                        Order::Pre => [Step::Visit(right), Step::Visit(left), Step::Yield(value)],
+                        Order::In => [Step::Visit(right), Step::Yield(value), Step::Visit(left)],
+                        Order::Post => [Step::Yield(value), Step::Visit(right), Step::Visit(left)],
+                    })
+                }
+            }
+        }
+    }
+}
+
+fn foldr_refs<T, U>(order: Order, f: &impl Fn(&T, U) -> U, x: &BinTree<T>, acc: U) -> U {
+    match x {
+        BinTree::Leaf => acc,
+        BinTree::Node(left, value, right) => match order {
+            Order::Pre => f(
+                value,
+                foldr_refs(order, f, left, foldr_refs(order, f, right, acc)),
+            ),
+            Order::In => foldr_refs(order, f, left, f(value, foldr_refs(order, f, right, acc))),
+            Order::Post => foldr_refs(order, f, left, foldr_refs(order, f, right, f(value, acc))),
+        },
+    }
+}
+
+fn foldr_values<T, U>(order: Order, f: &impl Fn(T, U) -> U, x: BinTree<T>, acc: U) -> U {
+    match x {
+        BinTree::Leaf => acc,
+        BinTree::Node(left, value, right) => match order {
+            Order::Pre => f(
+                value,
+                foldr_values(order, f, *left, foldr_values(order, f, *right, acc)),
+            ),
+            Order::In => foldr_values(
+                order,
+                f,
+                *left,
+                f(value, foldr_values(order, f, *right, acc)),
+            ),
+            Order::Post => foldr_values(
+                order,
+                f,
+                *left,
+                foldr_values(order, f, *right, f(value, acc)),
+            ),
+        },
+    }
+}
+
+// Runs the node's effect before, between or after its subtrees' effects
+fn sequence_in<TApplicative, T>(
+    order: Order,
+    x: BinTree<<TApplicative as WithTypeArg<T>>::Type>,
+) -> <TApplicative as WithTypeArg<BinTree<T>>>::Type
+where
+    TApplicative: LinearApplicative + gat::HigherKinded,
+{
+    match x {
+        BinTree::Leaf => <TApplicative as Lift>::lift(BinTree::Leaf),
+        BinTree::Node(left, value, right) => {
+            let left = sequence_in::<TApplicative, T>(order, *left);
+            let right = sequence_in::<TApplicative, T>(order, *right);
+            match order {
+                Order::Pre => <TApplicative as LinearApplicative>::llift2(
+                    |(value, left), right| BinTree::node(left, value, right),
+                    <TApplicative as LinearApplicative>::llift2(
+                        |value: T, left: BinTree<T>| (value, left),
+                        value,
+                        left,
+                    ),
+                    right,
+                ),
+                Order::In => <TApplicative as LinearApplicative>::llift2(
+                    |(left, value), right| BinTree::node(left, value, right),
+                    <TApplicative as LinearApplicative>::llift2(
+                        |left: BinTree<T>, value: T| (left, value),
+                        left,
+                        value,
+                    ),
+                    right,
+                ),
+                Order::Post => <TApplicative as LinearApplicative>::llift2(
+                    |(left, right), value| BinTree::node(left, value, right),
+                    <TApplicative as LinearApplicative>::llift2(
+                        |left: BinTree<T>, right: BinTree<T>| (left, right),
+                        left,
+                        right,
+                    ),
+                    value,
+                ),
+            }
+        }
+    }
+}
+
+type_con!(pub struct TypeCon for<T> BinTree<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        fn go<TIn, TOut>(f: &impl Fn(&TIn) -> TOut, x: &BinTree<TIn>) -> BinTree<TOut> {
+            match x {
+                BinTree::Leaf => BinTree::Leaf,
+                BinTree::Node(left, value, right) => {
+                    BinTree::node(go(f, left), f(value), go(f, right))
+                }
+            }
+        }
+        go(&f, x)
+    }
+}
+
+// Visits the values in order, so the default traversal runs effects in order.
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        fn go<TIn, TOut>(f: &impl Fn(TIn) -> TOut, x: BinTree<TIn>) -> BinTree<TOut> {
+            match x {
+                BinTree::Leaf => BinTree::Leaf,
+                BinTree::Node(left, value, right) => {
+                    let left = go(f, *left);
+                    let value = f(value);
+                    BinTree::node(left, value, go(f, *right))
+                }
+            }
+        }
+        go(&f, x)
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        foldr_values(Order::In, &f, x, init)
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        foldr_refs(Order::In, &f, x, init)
+    }
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        sequence_in::<TApplicative, T>(Order::In, x)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::*;
+
+type_con!(pub struct TypeCon for<T> Box<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        Box::new(f(x))
+    }
+}
+
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        Box::new(f(*x))
+    }
+}
+
+impl Lift for TypeCon {
+    fn lift<T>(x: T) -> <TypeCon as WithTypeArg<T>>::Type {
+        Box::new(x)
+    }
+}
+
+impl Applicative for TypeCon {
+    fn lift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        Box::new(f(x1, x2))
+    }
+}
+
+impl LinearApplicative for TypeCon {
+    fn llift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2) -> TOut,
+    {
+        Box::new(f(*x1, *x2))
+    }
+}
+
+impl Monad for TypeCon {
+    fn bind<TIn, TOut, F>(
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        f(x)
+    }
+}
+
+impl LinearMonad for TypeCon {
+    fn lbind<TIn, TOut, F>(
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: FnOnce(TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        f(*x)
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        f(*x, init)
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        f(x, init)
+    }
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        <TApplicative as LinearApplicative>::lap(<TApplicative as Lift>::lift(Box::new), *x)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+// Maps are functors over their values, with the keys left alone.
+type_con!(pub struct TypeCon<K> for<V> BTreeMap<K, V>);
+
+impl<K: Ord + Clone> Functor for TypeCon<K> {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        x.iter().map(|(k, v)| (k.clone(), f(v))).collect()
+    }
+}
+
+impl<K: Ord> LinearFunctor for TypeCon<K> {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        x.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<K: Ord + Clone> FunctorWithKey for TypeCon<K> {
+    type Key = K;
+
+    fn fmap_with_key<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&K, &TIn) -> TOut,
+    {
+        x.iter().map(|(k, v)| (k.clone(), f(k, v))).collect()
+    }
+
+    fn lmap_with_key<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&K, TIn) -> TOut,
+    {
+        x.into_iter()
+            .map(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            })
+            .collect()
+    }
+}
+
+// Combines the values of the keys which are in both maps.
+impl<K: Ord + Clone> Apply for TypeCon<K> {
+    fn lift_f2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        x1.iter()
+            .filter_map(|(k, v1)| x2.get(k).map(|v2| (k.clone(), f(v1, v2))))
+            .collect()
+    }
+}
+
+impl<K> LinearFoldable for TypeCon<K> {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_values()
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<K> Foldable for TypeCon<K> {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.values().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<K: Ord> LinearTraversable for TypeCon<K> {
+    // Each value is put straight back under its key, with the effects combined in key order.
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        x.into_iter().fold(
+            lift::<TApplicative, _>(BTreeMap::new()),
+            |acc, (k, y)| {
+                <TApplicative as LinearApplicative>::llift2(
+                    move |mut ys: BTreeMap<K, T>, y: T| {
+                        ys.insert(k, y);
+                        ys
+                    },
+                    acc,
+                    y,
+                )
+            },
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use crate::*;
+use std::collections::BTreeSet;
+
+type_con!(pub struct TypeCon for<T> BTreeSet<T>);
+
+impl<T: Ord> Constrained<T> for TypeCon {
+    fn from_elements<I>(iter: I) -> <Self as WithTypeArg<T>>::Type
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().collect()
+    }
+}
+
+impl CFunctor for TypeCon {
+    fn cmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TypeCon: Constrained<TIn> + Constrained<TOut>,
+        F: Fn(&TIn) -> TOut,
+    {
+        <TypeCon as Constrained<TOut>>::from_elements(x.iter().map(f))
+    }
+
+    fn lcmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TypeCon: Constrained<TIn> + Constrained<TOut>,
+        F: Fn(TIn) -> TOut,
+    {
+        <TypeCon as Constrained<TOut>>::from_elements(x.into_iter().map(f))
+    }
+}
+
+impl CLift for TypeCon {}
+
+impl CApplicative for TypeCon {
+    fn clift2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TypeCon: Constrained<TIn1> + Constrained<TIn2> + Constrained<TOut>,
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        let f = &f;
+        <TypeCon as Constrained<TOut>>::from_elements(
+            x1.iter()
+                .flat_map(|x1_val| x2.iter().map(move |x2_val| f(x1_val, x2_val))),
+        )
+    }
+}
+
+impl CMonad for TypeCon {
+    fn cbind<TIn, TOut, F>(
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TypeCon: Constrained<TIn> + Constrained<TOut>,
+        F: Fn(&TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        <TypeCon as Constrained<TOut>>::from_elements(x.iter().flat_map(f))
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_iter().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.iter().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::*;
+use std::cmp::Ordering;
+
+// A total ordering, which can be handed to `sort_by` through `compare`.
+#[allow(clippy::type_complexity)]
+pub struct Comparison<A>(pub Box<dyn Fn(&A, &A) -> Ordering>);
+
+impl<A> Comparison<A> {
+    pub fn new<F: Fn(&A, &A) -> Ordering + 'static>(f: F) -> Self {
+        Comparison(Box::new(f))
+    }
+
+    pub fn compare(&self, x: &A, y: &A) -> Ordering {
+        (self.0)(x, y)
+    }
+
+    pub fn reverse(self) -> Self
+    where
+        A: 'static,
+    {
+        Comparison::new(move |x, y| self.compare(y, x))
+    }
+}
+
+impl<A: Ord + 'static> Comparison<A> {
+    // The ordering from `Ord`
+    pub fn by_ord() -> Self {
+        Comparison::new(A::cmp)
+    }
+}
+
+type_con!(pub struct TypeCon for<A> Comparison<A>);
+
+impl Contravariant for TypeCon {
+    fn contramap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> TIn + 'static,
+        TIn: 'static,
+    {
+        Comparison::new(move |y, z| x.compare(&f(y), &f(z)))
+    }
+}
+
+// Compares by the first part, and then by the second part if those are equal
+impl Divisible for TypeCon {
+    fn divide<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> (TIn1, TIn2) + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Comparison::new(move |y, z| {
+            let (y1, y2) = f(y);
+            let (z1, z2) = f(z);
+            x1.compare(&y1, &z1).then_with(|| x2.compare(&y2, &z2))
+        })
+    }
+
+    fn conquer<T>() -> <TypeCon as WithTypeArg<T>>::Type {
+        Comparison::new(|_, _| Ordering::Equal)
+    }
+}
+
+// Lefts come before Rights
+impl Decidable for TypeCon {
+    fn choose<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> Either<TIn1, TIn2> + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Comparison::new(move |y, z| match (f(y), f(z)) {
+            (Either::Left(y1), Either::Left(z1)) => x1.compare(&y1, &z1),
+            (Either::Left(_), Either::Right(_)) => Ordering::Less,
+            (Either::Right(_), Either::Left(_)) => Ordering::Greater,
+            (Either::Right(y2), Either::Right(z2)) => x2.compare(&y2, &z2),
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use crate::*;
+
+// The environment comonad, which pairs a value with some read only context `E`. It's the dual of
+// the reader monad.
+type_con!(pub struct TypeCon<E> for<A> (E, A));
+
+impl<E: Clone> Functor for TypeCon<E> {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon<E> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        (x.0.clone(), f(&x.1))
+    }
+}
+
+impl<E> LinearFunctor for TypeCon<E> {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon<E> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        (x.0, f(x.1))
+    }
+}
+
+impl<E: Clone> Comonad for TypeCon<E> {
+    fn extract<T>(x: &<TypeCon<E> as WithTypeArg<T>>::Type) -> T
+    where
+        T: Clone,
+    {
+        x.1.clone()
+    }
+
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon<E> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&<TypeCon<E> as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        (x.0.clone(), f(x))
+    }
+}
+
+impl<E: Clone> LinearComonad for TypeCon<E> {
+    fn lextract<T>(x: <TypeCon<E> as WithTypeArg<T>>::Type) -> T {
+        x.1
+    }
+
+    fn lduplicate<T>(
+        x: <TypeCon<E> as WithTypeArg<T>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<<TypeCon<E> as WithTypeArg<T>>::Type>>::Type
+    where
+        T: Clone,
+    {
+        (x.0.clone(), x)
+    }
+}
+
+impl<E> LinearFoldable for TypeCon<E> {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        f(x.1, init)
+    }
+}
+
+impl<E> Foldable for TypeCon<E> {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        f(&x.1, init)
+    }
+}
+
+impl<E> LinearTraversable for TypeCon<E> {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        let (e, y) = x;
+        <TApplicative as LinearApplicative>::lap(
+            <TApplicative as Lift>::lift(move |y: T| (e, y)),
+            y,
+        )
+    }
+}
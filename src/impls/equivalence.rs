@@ -0,0 +1,81 @@
+use crate::*;
+
+// An equivalence relation
+#[allow(clippy::type_complexity)]
+pub struct Equivalence<A>(pub Box<dyn Fn(&A, &A) -> bool>);
+
+impl<A> Equivalence<A> {
+    pub fn new<F: Fn(&A, &A) -> bool + 'static>(f: F) -> Self {
+        Equivalence(Box::new(f))
+    }
+
+    pub fn equivalent(&self, x: &A, y: &A) -> bool {
+        (self.0)(x, y)
+    }
+}
+
+impl<A: PartialEq + 'static> Equivalence<A> {
+    // The equivalence from `PartialEq`
+    pub fn by_eq() -> Self {
+        Equivalence::new(A::eq)
+    }
+}
+
+type_con!(pub struct TypeCon for<A> Equivalence<A>);
+
+impl Contravariant for TypeCon {
+    fn contramap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> TIn + 'static,
+        TIn: 'static,
+    {
+        Equivalence::new(move |y, z| x.equivalent(&f(y), &f(z)))
+    }
+}
+
+// Both parts have to be equivalent
+impl Divisible for TypeCon {
+    fn divide<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> (TIn1, TIn2) + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Equivalence::new(move |y, z| {
+            let (y1, y2) = f(y);
+            let (z1, z2) = f(z);
+            x1.equivalent(&y1, &z1) && x2.equivalent(&y2, &z2)
+        })
+    }
+
+    fn conquer<T>() -> <TypeCon as WithTypeArg<T>>::Type {
+        Equivalence::new(|_, _| true)
+    }
+}
+
+// Values on different sides are never equivalent
+impl Decidable for TypeCon {
+    fn choose<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> Either<TIn1, TIn2> + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Equivalence::new(move |y, z| match (f(y), f(z)) {
+            (Either::Left(y1), Either::Left(z1)) => x1.equivalent(&y1, &z1),
+            (Either::Right(y2), Either::Right(z2)) => x2.equivalent(&y2, &z2),
+            _ => false,
+        })
+    }
+}
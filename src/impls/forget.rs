@@ -0,0 +1,66 @@
+use crate::*;
+use std::marker::PhantomData;
+
+// A function to `R` which ignores the profunctor's output type, so `dimap` only uses its first
+// function. Optics use it to get values out of a structure.
+pub struct Forget<R, A, B>(pub Box<dyn Fn(A) -> R>, PhantomData<fn() -> B>);
+
+impl<R, A, B> Forget<R, A, B> {
+    pub fn new<F: Fn(A) -> R + 'static>(f: F) -> Self {
+        Forget(Box::new(f), PhantomData)
+    }
+
+    pub fn run(&self, x: A) -> R {
+        (self.0)(x)
+    }
+}
+
+type_con!(pub struct TypeCon<R> for<A, B> Forget<R, A, B>);
+
+impl<R: 'static> Profunctor for TypeCon<R> {
+    fn dimap<A, B, C, D, F, G>(
+        f: F,
+        _g: G,
+        x: <TypeCon<R> as WithTypeArgs2<B, C>>::Type,
+    ) -> <TypeCon<R> as WithTypeArgs2<A, D>>::Type
+    where
+        F: Fn(A) -> B + 'static,
+        G: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        Forget::new(move |y| x.run(f(y)))
+    }
+}
+
+impl<R: 'static> Strong for TypeCon<R> {
+    fn first<A, B, C>(
+        x: <TypeCon<R> as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon<R> as WithTypeArgs2<(A, C), (B, C)>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: Clone + 'static,
+    {
+        Forget::new(move |(y, _): (A, C)| x.run(y))
+    }
+}
+
+// Rights have nothing to get, so give the empty value
+impl<R: Monoid + 'static> Choice for TypeCon<R> {
+    fn left<A, B, C>(
+        x: <TypeCon<R> as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon<R> as WithTypeArgs2<Either<A, C>, Either<B, C>>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        Forget::new(move |y| match y {
+            Either::Left(y1) => x.run(y1),
+            Either::Right(_) => R::default(),
+        })
+    }
+}
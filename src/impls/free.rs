@@ -0,0 +1,324 @@
+use crate::*;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+// The free monad over a functor `F`: either a value, or one layer of `F` holding the rest of the
+// computation. `F` is usually an enum of instructions, so a `Free<F, A>` is a program which is
+// just data, and an interpreter gives it a meaning with `fold_free`.
+//
+// `bind` and `fmap` go straight to the end of the program and rebuild it, so like in Haskell,
+// long chains of left nested binds take quadratic time.
+//
+// An instruction which asks the interpreter for an `X` holds a continuation, like
+// `Rc<dyn Fn(X) -> Next>`. `fmap` can't keep its function inside its result, so those
+// instructions can't be `Functor`s, and implement `StaticFunctor` instead. Programs over them are
+// built with `lift_f_static` and the `map` and `bind` methods, rather than `mdo!`.
+pub enum Free<F, A>
+where
+    F: gat::HigherKinded,
+{
+    Pure(A),
+    Roll(Box<<F as WithTypeArg<Free<F, A>>>::Type>),
+}
+
+pub struct TypeCon<F> {
+    _unused: PhantomData<F>,
+}
+
+impl<F, A> TypeAppParam for Free<F, A>
+where
+    F: gat::HigherKinded,
+{
+    type Param = A;
+}
+
+impl<F, A> TypeApp<TypeCon<F>, A> for Free<F, A> where F: gat::HigherKinded {}
+
+impl<F> gat::HigherKinded for TypeCon<F>
+where
+    F: gat::HigherKinded,
+{
+    type Of<A> = Free<F, A>;
+}
+
+// Lifts one instruction into a program which runs it and returns its result.
+pub fn lift_f<F, A, X>(x: X) -> Free<F, A>
+where
+    F: gat::HigherKinded + LinearFunctor,
+    X: TypeApp<F, A>,
+{
+    Free::Roll(Box::new(<F as LinearFunctor>::lmap(
+        Free::Pure,
+        x.into_val(),
+    )))
+}
+
+// A functor which can only be mapped with functions it can keep, which an instruction holding a
+// continuation needs. The function is shared, as an instruction may hold more than one
+// continuation.
+pub trait StaticFunctor: gat::HigherKinded + 'static {
+    fn map_static<TIn, TOut>(
+        f: Rc<dyn Fn(TIn) -> TOut>,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        TIn: 'static,
+        TOut: 'static;
+}
+
+// Lifts one instruction into a program which runs it and returns its result, for instructions
+// which hold continuations.
+pub fn lift_f_static<F, A, X>(x: X) -> Free<F, A>
+where
+    F: StaticFunctor,
+    A: 'static,
+    X: TypeApp<F, A>,
+{
+    Free::Roll(Box::new(<F as StaticFunctor>::map_static(
+        Rc::new(Free::Pure),
+        x.into_val(),
+    )))
+}
+
+impl<F, A> Free<F, A>
+where
+    F: StaticFunctor,
+    A: 'static,
+{
+    pub fn map<B, G>(self, f: G) -> Free<F, B>
+    where
+        B: 'static,
+        G: Fn(A) -> B + 'static,
+    {
+        self.bind(move |y| Free::Pure(f(y)))
+    }
+
+    pub fn bind<B, G>(self, f: G) -> Free<F, B>
+    where
+        B: 'static,
+        G: Fn(A) -> Free<F, B> + 'static,
+    {
+        bind_static(self, Rc::new(f))
+    }
+}
+
+fn bind_static<F, A, B>(x: Free<F, A>, f: Rc<dyn Fn(A) -> Free<F, B>>) -> Free<F, B>
+where
+    F: StaticFunctor,
+    A: 'static,
+    B: 'static,
+{
+    match x {
+        Free::Pure(y) => f(y),
+        Free::Roll(y) => Free::Roll(Box::new(<F as StaticFunctor>::map_static(
+            Rc::new(move |rest| bind_static(rest, f.clone())),
+            *y,
+        ))),
+    }
+}
+
+// A natural transformation from `F` to `G`. Haskell's is a function `forall x. f x -> g x`,
+// which a closure can't be, so it's a trait with a generic method instead.
+//
+// It takes the instruction by value, so an instruction's continuation can be called with the
+// interpreter's answer.
+pub trait NaturalTransformation<F, G>
+where
+    F: gat::HigherKinded,
+    G: gat::HigherKinded,
+{
+    fn transform<T>(&self, x: <F as WithTypeArg<T>>::Type) -> <G as WithTypeArg<T>>::Type;
+}
+
+// Runs a program in the monad `M`, translating each instruction with `nt`.
+pub fn fold_free<F, M, A, N>(nt: &N, x: Free<F, A>) -> <M as WithTypeArg<A>>::Type
+where
+    F: gat::HigherKinded,
+    M: gat::HigherKinded + LinearMonadMut,
+    N: NaturalTransformation<F, M>,
+{
+    match x {
+        Free::Pure(y) => <M as Lift>::lift(y),
+        Free::Roll(y) => <M as LinearMonadMut>::lbind_mut(nt.transform::<Free<F, A>>(*y), |rest| {
+            fold_free::<F, M, A, N>(nt, rest)
+        }),
+    }
+}
+
+fn fmap_free<F, TIn, TOut, G>(f: &G, x: &Free<F, TIn>) -> Free<F, TOut>
+where
+    F: gat::HigherKinded + Functor,
+    G: Fn(&TIn) -> TOut,
+{
+    match x {
+        Free::Pure(y) => Free::Pure(f(y)),
+        Free::Roll(y) => Free::Roll(Box::new(<F as Functor>::fmap(
+            |rest| fmap_free(f, rest),
+            &**y,
+        ))),
+    }
+}
+
+fn lmap_free<F, TIn, TOut, G>(f: &G, x: Free<F, TIn>) -> Free<F, TOut>
+where
+    F: gat::HigherKinded + LinearFunctor,
+    G: Fn(TIn) -> TOut,
+{
+    match x {
+        Free::Pure(y) => Free::Pure(f(y)),
+        Free::Roll(y) => Free::Roll(Box::new(<F as LinearFunctor>::lmap(
+            |rest| lmap_free(f, rest),
+            *y,
+        ))),
+    }
+}
+
+fn bind_free<F, TIn, TOut, G>(x: &Free<F, TIn>, f: &G) -> Free<F, TOut>
+where
+    F: gat::HigherKinded + Functor,
+    G: Fn(&TIn) -> Free<F, TOut>,
+{
+    match x {
+        Free::Pure(y) => f(y),
+        Free::Roll(y) => Free::Roll(Box::new(<F as Functor>::fmap(
+            |rest| bind_free(rest, f),
+            &**y,
+        ))),
+    }
+}
+
+impl<F> Functor for TypeCon<F>
+where
+    F: gat::HigherKinded + Functor,
+{
+    fn fmap<TIn, TOut, G>(
+        f: G,
+        x: &<TypeCon<F> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<F> as WithTypeArg<TOut>>::Type
+    where
+        G: Fn(&TIn) -> TOut,
+    {
+        fmap_free(&f, x)
+    }
+}
+
+impl<F> LinearFunctor for TypeCon<F>
+where
+    F: gat::HigherKinded + LinearFunctor,
+{
+    fn lmap<TIn, TOut, G>(
+        f: G,
+        x: <TypeCon<F> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<F> as WithTypeArg<TOut>>::Type
+    where
+        G: Fn(TIn) -> TOut,
+    {
+        lmap_free(&f, x)
+    }
+}
+
+impl<F> Lift for TypeCon<F>
+where
+    F: gat::HigherKinded,
+{
+    fn lift<T>(x: T) -> <TypeCon<F> as WithTypeArg<T>>::Type {
+        Free::Pure(x)
+    }
+}
+
+impl<F> Applicative for TypeCon<F>
+where
+    F: gat::HigherKinded + Functor,
+{
+    fn lift2<TIn1, TIn2, TOut, G>(
+        f: G,
+        x1: &<TypeCon<F> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<F> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<F> as WithTypeArg<TOut>>::Type
+    where
+        G: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        bind_free(x1, &|y1: &TIn1| fmap_free(&|y2: &TIn2| f(y1, y2), x2))
+    }
+}
+
+impl<F> Monad for TypeCon<F>
+where
+    F: gat::HigherKinded + Functor,
+{
+    fn bind<TIn, TOut, G>(
+        x: &<TypeCon<F> as WithTypeArg<TIn>>::Type,
+        f: G,
+    ) -> <TypeCon<F> as WithTypeArg<TOut>>::Type
+    where
+        G: Fn(&TIn) -> <TypeCon<F> as WithTypeArg<TOut>>::Type,
+    {
+        bind_free(x, &f)
+    }
+}
+
+// The second program is copied with `fmap` for each result of the first.
+impl<F> LinearApplicativeMut for TypeCon<F>
+where
+    F: gat::HigherKinded + Functor,
+{
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <TypeCon<F> as WithTypeArg<TFunc>>::Type,
+        x: <TypeCon<F> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<F> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn) -> TOut,
+        TIn: Clone,
+    {
+        <TypeCon<F> as LinearMonadMut>::lbind_mut(f, |mut g: TFunc| {
+            let x = fmap_free(&TIn::clone, &x);
+            <TypeCon<F> as LinearMonadMut>::lbind_mut(x, |y| Free::Pure(g(y)))
+        })
+    }
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        mut f: TFunc,
+        x1: <TypeCon<F> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<F> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<F> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        <TypeCon<F> as LinearMonadMut>::lbind_mut(x1, |y1: TIn1| {
+            let x2 = fmap_free(&TIn2::clone, &x2);
+            <TypeCon<F> as LinearMonadMut>::lbind_mut(x2, |y2| Free::Pure(f(y1.clone(), y2)))
+        })
+    }
+}
+
+// `F`'s `lmap` takes an `Fn`, so the `FnMut` is reached through a `RefCell`. It's only borrowed
+// while it runs, and it can't call back into the traversal, so the borrows never overlap.
+impl<F> LinearMonadMut for TypeCon<F>
+where
+    F: gat::HigherKinded + Functor,
+{
+    fn lbind_mut<TIn, TOut, G>(
+        x: <TypeCon<F> as WithTypeArg<TIn>>::Type,
+        f: G,
+    ) -> <TypeCon<F> as WithTypeArg<TOut>>::Type
+    where
+        G: FnMut(TIn) -> <TypeCon<F> as WithTypeArg<TOut>>::Type,
+    {
+        fn go<F, TIn, TOut, G>(x: Free<F, TIn>, f: &RefCell<G>) -> Free<F, TOut>
+        where
+            F: gat::HigherKinded + LinearFunctor,
+            G: FnMut(TIn) -> Free<F, TOut>,
+        {
+            match x {
+                Free::Pure(y) => (f.borrow_mut())(y),
+                Free::Roll(y) => {
+                    Free::Roll(Box::new(<F as LinearFunctor>::lmap(|rest| go(rest, f), *y)))
+                }
+            }
+        }
+        go(x, &RefCell::new(f))
+    }
+}
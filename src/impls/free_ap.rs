@@ -0,0 +1,289 @@
+use crate::*;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+// The free applicative over a functor `F`. Unlike `Free`, later instructions can't depend on the
+// results of earlier ones, so all of a `FreeAp`'s instructions are known before it runs. That
+// means it can be run for its effects with `run_ap`, or just looked at with `run_ap_monoid`, e.g.
+// to list all the config keys or command line options a parser will read.
+//
+// Haskell's `Ap (f x) (Ap f (x -> a))` hides the type `x` of each instruction's result. Rust can
+// only hide types behind trait objects, whose methods can't be generic in the applicative a
+// program is run in, and a function recursing into `Ap f (x -> a)` would need a new copy of
+// itself for each instruction. So instructions are kept in the order they'll run, with their
+// results as `Answer`s, along with the steps which combine the results at the end.
+//
+// The types are still checked where it matters. An instruction is an `F<T>` answered with a `T`,
+// which it holds, like a `Vec<T>` of choices, or makes with a continuation, like
+// `Rc<dyn Fn(u16) -> T>` for one answered with a number. An interpreter is a
+// `NaturalTransformation`, which works for any `T`, so it can only answer with what the
+// instruction gives it, and can't make an `Answer` of its own.
+//
+// As the combining functions are kept, `map` and `lift2` need `'static` functions, which the
+// crate's `Functor` and `Applicative` don't ask for, so `FreeAp` isn't one of those, and has its
+// own.
+pub struct FreeAp<F, A>
+where
+    F: gat::HigherKinded,
+{
+    instructions: VecDeque<<F as WithTypeArg<Answer>>::Type>,
+    steps: VecDeque<Step>,
+    finish: fn(Value) -> A,
+}
+
+// An instruction's result, with its type hidden. Only `lift_ap` and `lift_ap_static` make them.
+#[derive(Clone)]
+pub struct Answer(Rc<dyn Any>);
+
+// The results are combined by a stack machine, so running a program doesn't recurse however
+// deeply its `lift2`s and `map`s were nested. `lift2` puts its arguments' steps one after the
+// other, then its own, so the steps are in postfix order. They're built by the typed functions
+// below, so each one finds values of the types it expects on the stack.
+enum Step {
+    // Pushes the next instruction's result
+    Next,
+    Pure(Value),
+    Map(Box<MapFn>),
+    Lift2(Box<Lift2Fn>),
+}
+
+// A value on the stack, with its type hidden
+type Value = Rc<dyn Any>;
+
+type MapFn = dyn Fn(&dyn Any) -> Value;
+
+type Lift2Fn = dyn Fn(&dyn Any, &dyn Any) -> Value;
+
+fn downcast<A: 'static>(x: &dyn Any) -> &A {
+    match x.downcast_ref::<A>() {
+        Some(y) => y,
+        None => unreachable!("a step is only given values of the types it was built with"),
+    }
+}
+
+// The last step of `map` and `lift2` makes a new value, so it can be moved out.
+fn take<A: 'static>(x: Value) -> A {
+    match Rc::downcast::<A>(x).map(Rc::try_unwrap) {
+        Ok(Ok(y)) => y,
+        _ => unreachable!("the result of the last step is a new value"),
+    }
+}
+
+// `pure` and one instruction's result are shared with the program and the other runs.
+fn copy<A: Clone + 'static>(x: Value) -> A {
+    downcast::<A>(&*x).clone()
+}
+
+fn pop(stack: &mut Vec<Value>) -> Value {
+    match stack.pop() {
+        Some(x) => x,
+        None => unreachable!("each step's arguments are pushed before it"),
+    }
+}
+
+// Appends `back` to `front`, moving whichever is shorter, so building a program takes
+// `O(n log n)` time however it's nested.
+fn concat<T>(mut front: VecDeque<T>, mut back: VecDeque<T>) -> VecDeque<T> {
+    if front.len() >= back.len() {
+        front.append(&mut back);
+        front
+    } else {
+        while let Some(x) = front.pop_back() {
+            back.push_front(x);
+        }
+        back
+    }
+}
+
+impl<F, A> FreeAp<F, A>
+where
+    F: gat::HigherKinded,
+    A: 'static,
+{
+    // A program with no instructions, which returns `x`.
+    pub fn pure(x: A) -> Self
+    where
+        A: Clone,
+    {
+        FreeAp {
+            instructions: VecDeque::new(),
+            steps: VecDeque::from(vec![Step::Pure(Rc::new(x))]),
+            finish: copy::<A>,
+        }
+    }
+
+    pub fn map<B, G>(mut self, f: G) -> FreeAp<F, B>
+    where
+        B: 'static,
+        G: Fn(&A) -> B + 'static,
+    {
+        self.steps
+            .push_back(Step::Map(Box::new(move |x| Rc::new(f(downcast::<A>(x))))));
+        FreeAp {
+            instructions: self.instructions,
+            steps: self.steps,
+            finish: take::<B>,
+        }
+    }
+
+    // Runs `x1`'s instructions and then `x2`'s, and combines their results with `f`.
+    pub fn lift2<B, C, G>(f: G, x1: FreeAp<F, A>, x2: FreeAp<F, B>) -> FreeAp<F, C>
+    where
+        B: 'static,
+        C: 'static,
+        G: Fn(&A, &B) -> C + 'static,
+    {
+        let mut steps = concat(x1.steps, x2.steps);
+        steps.push_back(Step::Lift2(Box::new(move |x1, x2| {
+            Rc::new(f(downcast::<A>(x1), downcast::<B>(x2)))
+        })));
+        FreeAp {
+            instructions: concat(x1.instructions, x2.instructions),
+            steps,
+            finish: take::<C>,
+        }
+    }
+
+    // The instructions, in order.
+    pub fn instructions(&self) -> Vec<&<F as WithTypeArg<Answer>>::Type> {
+        self.instructions.iter().collect()
+    }
+
+    fn combine(&self, answers: Vec<Answer>) -> A {
+        let mut answers = answers.into_iter();
+        let mut stack: Vec<Value> = Vec::new();
+        for step in &self.steps {
+            let value = match step {
+                Step::Next => match answers.next() {
+                    Some(answer) => answer.0,
+                    None => unreachable!("there's an answer for each instruction"),
+                },
+                Step::Pure(x) => x.clone(),
+                Step::Map(f) => f(&*pop(&mut stack)),
+                Step::Lift2(f) => {
+                    let x2 = pop(&mut stack);
+                    f(&*pop(&mut stack), &*x2)
+                }
+            };
+            stack.push(value);
+        }
+        (self.finish)(pop(&mut stack))
+    }
+}
+
+fn lift_answered<F, A>(instruction: <F as WithTypeArg<Answer>>::Type) -> FreeAp<F, A>
+where
+    F: gat::HigherKinded,
+    A: Clone + 'static,
+{
+    FreeAp {
+        instructions: VecDeque::from(vec![instruction]),
+        steps: VecDeque::from(vec![Step::Next]),
+        finish: copy::<A>,
+    }
+}
+
+// Lifts one instruction into a program which runs it and returns its result.
+pub fn lift_ap<F, A, X>(x: X) -> FreeAp<F, A>
+where
+    F: gat::HigherKinded + LinearFunctor,
+    A: Clone + 'static,
+    X: TypeApp<F, A>,
+{
+    lift_answered(<F as LinearFunctor>::lmap(
+        |y: A| Answer(Rc::new(y)),
+        x.into_val(),
+    ))
+}
+
+// Lifts one instruction into a program which runs it and returns its result, for instructions
+// which hold continuations.
+pub fn lift_ap_static<F, A, X>(x: X) -> FreeAp<F, A>
+where
+    F: StaticFunctor,
+    A: Clone + 'static,
+    X: TypeApp<F, A>,
+{
+    lift_answered(<F as StaticFunctor>::map_static(
+        Rc::new(|y: A| Answer(Rc::new(y))),
+        x.into_val(),
+    ))
+}
+
+// The results so far, last first, so adding one doesn't copy the others.
+struct Answers {
+    last: Answer,
+    rest: Option<Rc<Answers>>,
+}
+
+// A long program's list is too long to drop recursively.
+impl Drop for Answers {
+    fn drop(&mut self) {
+        let mut rest = self.rest.take();
+        while let Some(answers) = rest {
+            rest = match Rc::try_unwrap(answers) {
+                Ok(mut answers) => answers.rest.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+fn to_vec(answers: &Option<Rc<Answers>>, len: usize) -> Vec<Answer> {
+    let mut result = Vec::with_capacity(len);
+    let mut answers = answers;
+    while let Some(x) = answers {
+        result.push(x.last.clone());
+        answers = &x.rest;
+    }
+    result.reverse();
+    result
+}
+
+// Runs a program in the applicative `M`, with `nt` running each instruction, and `lift2` putting
+// their results together.
+pub fn run_ap<F, M, A, N>(nt: &N, mut x: FreeAp<F, A>) -> <M as WithTypeArg<A>>::Type
+where
+    F: gat::HigherKinded,
+    M: gat::HigherKinded + Applicative,
+    A: 'static,
+    N: NaturalTransformation<F, M>,
+{
+    let len = x.instructions.len();
+    let answers = std::mem::take(&mut x.instructions).into_iter().fold(
+        <M as Lift>::lift(None),
+        |acc, instruction| {
+            <M as Applicative>::lift2(
+                |answers: &Option<Rc<Answers>>, answer: &Answer| {
+                    Some(Rc::new(Answers {
+                        last: answer.clone(),
+                        rest: answers.clone(),
+                    }))
+                },
+                &acc,
+                &nt.transform::<Answer>(instruction),
+            )
+        },
+    );
+    <M as Functor>::fmap(
+        |answers: &Option<Rc<Answers>>| x.combine(to_vec(answers, len)),
+        &answers,
+    )
+}
+
+// Summarises a program without running it, by combining `f` of each instruction with the
+// Monoid `M`.
+pub fn run_ap_monoid<F, M, A, G>(f: G, x: &FreeAp<F, A>) -> M
+where
+    F: gat::HigherKinded,
+    M: Monoid,
+    A: 'static,
+    G: Fn(&<F as WithTypeArg<Answer>>::Type) -> M,
+{
+    x.instructions()
+        .into_iter()
+        .fold(M::default(), |acc, instruction| {
+            acc.semigroup_op(f(instruction))
+        })
+}
@@ -0,0 +1,87 @@
+use crate::*;
+
+// Boxed functions, as a profunctor
+pub type Func<A, B> = Box<dyn Fn(A) -> B>;
+
+type_con!(pub struct TypeCon for<A, B> Box<dyn Fn(A) -> B>);
+
+impl Profunctor for TypeCon {
+    fn dimap<A, B, C, D, F, G>(
+        f: F,
+        g: G,
+        x: <TypeCon as WithTypeArgs2<B, C>>::Type,
+    ) -> <TypeCon as WithTypeArgs2<A, D>>::Type
+    where
+        F: Fn(A) -> B + 'static,
+        G: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        Box::new(move |y| g(x(f(y))))
+    }
+}
+
+impl Strong for TypeCon {
+    fn first<A, B, C>(
+        x: <TypeCon as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon as WithTypeArgs2<(A, C), (B, C)>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: Clone + 'static,
+    {
+        Box::new(move |(y, z)| (x(y), z))
+    }
+}
+
+impl Choice for TypeCon {
+    fn left<A, B, C>(
+        x: <TypeCon as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon as WithTypeArgs2<Either<A, C>, Either<B, C>>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        Box::new(move |y| match y {
+            Either::Left(y1) => Either::Left(x(y1)),
+            Either::Right(y2) => Either::Right(y2),
+        })
+    }
+}
+
+impl Category for TypeCon {
+    fn id<A>() -> <TypeCon as WithTypeArgs2<A, A>>::Type
+    where
+        A: 'static,
+    {
+        Box::new(|x| x)
+    }
+
+    fn compose<A, B, C>(
+        f: <TypeCon as WithTypeArgs2<B, C>>::Type,
+        g: <TypeCon as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon as WithTypeArgs2<A, C>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        Box::new(move |x| f(g(x)))
+    }
+}
+
+impl Arrow for TypeCon {
+    fn arr<A, B, F>(f: F) -> <TypeCon as WithTypeArgs2<A, B>>::Type
+    where
+        F: Fn(A) -> B + 'static,
+        A: 'static,
+        B: 'static,
+    {
+        Box::new(f)
+    }
+}
+
+impl ArrowChoice for TypeCon {}
@@ -0,0 +1,129 @@
+use crate::*;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+// Like BTreeMap, but the values are visited in an arbitrary order, so `foldr` and `traverse`
+// should only be used with functions where the order doesn't matter.
+type_con!(pub struct TypeCon<K, S> for<V> HashMap<K, V, S>);
+
+impl<K: Eq + Hash + Clone, S: BuildHasher + Clone> Functor for TypeCon<K, S> {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        let mut result = HashMap::with_capacity_and_hasher(x.len(), x.hasher().clone());
+        result.extend(x.iter().map(|(k, v)| (k.clone(), f(v))));
+        result
+    }
+}
+
+impl<K: Eq + Hash, S: BuildHasher + Clone> LinearFunctor for TypeCon<K, S> {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        let mut result = HashMap::with_capacity_and_hasher(x.len(), x.hasher().clone());
+        result.extend(x.into_iter().map(|(k, v)| (k, f(v))));
+        result
+    }
+}
+
+impl<K: Eq + Hash + Clone, S: BuildHasher + Clone> FunctorWithKey for TypeCon<K, S> {
+    type Key = K;
+
+    fn fmap_with_key<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&K, &TIn) -> TOut,
+    {
+        let mut result = HashMap::with_capacity_and_hasher(x.len(), x.hasher().clone());
+        result.extend(x.iter().map(|(k, v)| (k.clone(), f(k, v))));
+        result
+    }
+
+    fn lmap_with_key<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&K, TIn) -> TOut,
+    {
+        let mut result = HashMap::with_capacity_and_hasher(x.len(), x.hasher().clone());
+        result.extend(x.into_iter().map(|(k, v)| {
+            let v = f(&k, v);
+            (k, v)
+        }));
+        result
+    }
+}
+
+// Combines the values of the keys which are in both maps.
+impl<K: Eq + Hash + Clone, S: BuildHasher + Clone> Apply for TypeCon<K, S> {
+    fn lift_f2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        let mut result = HashMap::with_hasher(x1.hasher().clone());
+        result.extend(
+            x1.iter()
+                .filter_map(|(k, v1)| x2.get(k).map(|v2| (k.clone(), f(v1, v2)))),
+        );
+        result
+    }
+}
+
+impl<K, S> LinearFoldable for TypeCon<K, S> {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_values().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<K, S> Foldable for TypeCon<K, S> {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.values().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<K: Eq + Hash, S: BuildHasher + Clone> LinearTraversable for TypeCon<K, S> {
+    // Each value is put straight back under its key, with the effects combined in the map's
+    // iteration order.
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        let hasher = x.hasher().clone();
+        x.into_iter().fold(
+            lift::<TApplicative, _>(HashMap::with_hasher(hasher)),
+            |acc, (k, y)| {
+                <TApplicative as LinearApplicative>::llift2(
+                    move |mut ys: HashMap<K, T, S>, y: T| {
+                        ys.insert(k, y);
+                        ys
+                    },
+                    acc,
+                    y,
+                )
+            },
+        )
+    }
+}
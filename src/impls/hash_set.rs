@@ -0,0 +1,91 @@
+use crate::*;
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+// The elements are visited in an arbitrary order, so `foldr` should only be used with
+// functions where the order doesn't matter.
+type_con!(pub struct TypeCon<S> for<T> HashSet<T, S>);
+
+impl<T: Eq + Hash, S: BuildHasher + Default> Constrained<T> for TypeCon<S> {
+    fn from_elements<I>(iter: I) -> <Self as WithTypeArg<T>>::Type
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().collect()
+    }
+}
+
+impl<S> CFunctor for TypeCon<S> {
+    fn cmap<TIn, TOut, F>(
+        f: F,
+        x: &<Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn> + Constrained<TOut>,
+        F: Fn(&TIn) -> TOut,
+    {
+        <Self as Constrained<TOut>>::from_elements(x.iter().map(f))
+    }
+
+    fn lcmap<TIn, TOut, F>(
+        f: F,
+        x: <Self as WithTypeArg<TIn>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn> + Constrained<TOut>,
+        F: Fn(TIn) -> TOut,
+    {
+        <Self as Constrained<TOut>>::from_elements(x.into_iter().map(f))
+    }
+}
+
+impl<S> CLift for TypeCon<S> {}
+
+impl<S> CApplicative for TypeCon<S> {
+    fn clift2<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: &<Self as WithTypeArg<TIn1>>::Type,
+        x2: &<Self as WithTypeArg<TIn2>>::Type,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn1> + Constrained<TIn2> + Constrained<TOut>,
+        F: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        let f = &f;
+        <Self as Constrained<TOut>>::from_elements(
+            x1.iter()
+                .flat_map(|x1_val| x2.iter().map(move |x2_val| f(x1_val, x2_val))),
+        )
+    }
+}
+
+impl<S> CMonad for TypeCon<S> {
+    fn cbind<TIn, TOut, F>(
+        x: &<Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: Constrained<TIn> + Constrained<TOut>,
+        F: Fn(&TIn) -> <Self as WithTypeArg<TOut>>::Type,
+    {
+        <Self as Constrained<TOut>>::from_elements(x.iter().flat_map(f))
+    }
+}
+
+impl<S> LinearFoldable for TypeCon<S> {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_iter().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<S> Foldable for TypeCon<S> {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.iter().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
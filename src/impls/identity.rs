@@ -0,0 +1,151 @@
+use crate::*;
+
+// The trivial functor, holding exactly one value with no other context.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity<T>(pub T);
+
+type_con!(pub struct TypeCon for<T> Identity<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        Identity(f(&x.0))
+    }
+}
+
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        Identity(f(x.0))
+    }
+}
+
+impl Lift for TypeCon {
+    fn lift<T>(x: T) -> <TypeCon as WithTypeArg<T>>::Type {
+        Identity(x)
+    }
+}
+
+impl Applicative for TypeCon {
+    fn lift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        Identity(f(&x1.0, &x2.0))
+    }
+}
+
+impl LinearApplicative for TypeCon {
+    fn llift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2) -> TOut,
+    {
+        Identity(f(x1.0, x2.0))
+    }
+}
+
+impl Monad for TypeCon {
+    fn bind<TIn, TOut, F>(
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        f(&x.0)
+    }
+}
+
+impl LinearMonad for TypeCon {
+    fn lbind<TIn, TOut, F>(
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: FnOnce(TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        f(x.0)
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        f(x.0, init)
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        f(&x.0, init)
+    }
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        <TApplicative as LinearApplicative>::lap(<TApplicative as Lift>::lift(Identity), x.0)
+    }
+}
+
+impl Comonad for TypeCon {
+    fn extract<T>(x: &<TypeCon as WithTypeArg<T>>::Type) -> T
+    where
+        T: Clone,
+    {
+        x.0.clone()
+    }
+
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&<TypeCon as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        Identity(f(x))
+    }
+}
+
+impl LinearComonad for TypeCon {
+    fn lextract<T>(x: <TypeCon as WithTypeArg<T>>::Type) -> T {
+        x.0
+    }
+
+    fn lduplicate<T>(
+        x: <TypeCon as WithTypeArg<T>>::Type,
+    ) -> <TypeCon as WithTypeArg<<TypeCon as WithTypeArg<T>>::Type>>::Type
+    where
+        T: Clone,
+    {
+        Identity(x)
+    }
+}
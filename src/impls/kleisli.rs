@@ -0,0 +1,146 @@
+use crate::*;
+use std::marker::PhantomData;
+
+// A function returning a monadic value, `A -> TCon<B>`, as a profunctor.
+//
+// The instances move values into `lmap` and `lbind_mut`, so they need `TCon` to be a
+// `LinearFunctor`, and for `Category` and `Arrow`, a `LinearMonadMut`. Every `Monad` in this
+// library is one, including `Tree`, `BinTree` and `Free`, which clone where a value is used more
+// than once. A `Monad` alone isn't enough, as `bind` only lends out the values, and `compose`
+// has no `Clone` bound to copy them with.
+pub struct Kleisli<TCon, A, B>(pub Box<dyn Fn(A) -> <TCon as WithTypeArg<B>>::Type>)
+where
+    TCon: WithTypeArg<B> + ?Sized;
+
+impl<TCon, A, B> Kleisli<TCon, A, B>
+where
+    TCon: WithTypeArg<B> + ?Sized,
+{
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> <TCon as WithTypeArg<B>>::Type + 'static,
+    {
+        Kleisli(Box::new(f))
+    }
+
+    pub fn run(&self, x: A) -> <TCon as WithTypeArg<B>>::Type {
+        (self.0)(x)
+    }
+}
+
+pub struct TypeCon<TCon: ?Sized> {
+    _unused: PhantomData<TCon>,
+}
+
+impl<TCon, A, B> TypeApp2<TypeCon<TCon>, A, B> for Kleisli<TCon, A, B> where
+    TCon: WithTypeArg<B> + ?Sized
+{
+}
+
+impl<TCon, A, B> WithTypeArgs2<A, B> for TypeCon<TCon>
+where
+    TCon: WithTypeArg<B> + ?Sized,
+{
+    type Type = Kleisli<TCon, A, B>;
+}
+
+impl<TCon> Profunctor for TypeCon<TCon>
+where
+    TCon: gat::HigherKinded + LinearFunctor + 'static,
+{
+    fn dimap<A, B, C, D, F, G>(
+        f: F,
+        g: G,
+        x: <TypeCon<TCon> as WithTypeArgs2<B, C>>::Type,
+    ) -> <TypeCon<TCon> as WithTypeArgs2<A, D>>::Type
+    where
+        F: Fn(A) -> B + 'static,
+        G: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        Kleisli::new(move |y| <TCon as LinearFunctor>::lmap(&g, x.run(f(y))))
+    }
+}
+
+impl<TCon> Strong for TypeCon<TCon>
+where
+    TCon: gat::HigherKinded + LinearFunctor + 'static,
+{
+    fn first<A, B, C>(
+        x: <TypeCon<TCon> as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon<TCon> as WithTypeArgs2<(A, C), (B, C)>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: Clone + 'static,
+    {
+        Kleisli::new(move |(y, z): (A, C)| {
+            <TCon as LinearFunctor>::lmap(move |y2| (y2, z.clone()), x.run(y))
+        })
+    }
+}
+
+impl<TCon> Choice for TypeCon<TCon>
+where
+    TCon: gat::HigherKinded + LinearFunctor + Lift + 'static,
+{
+    fn left<A, B, C>(
+        x: <TypeCon<TCon> as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon<TCon> as WithTypeArgs2<Either<A, C>, Either<B, C>>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        Kleisli::new(move |y| match y {
+            Either::Left(y1) => <TCon as LinearFunctor>::lmap(Either::Left, x.run(y1)),
+            Either::Right(y2) => <TCon as Lift>::lift(Either::Right(y2)),
+        })
+    }
+}
+
+impl<TCon> Category for TypeCon<TCon>
+where
+    TCon: gat::HigherKinded + LinearMonadMut + 'static,
+{
+    fn id<A>() -> <TypeCon<TCon> as WithTypeArgs2<A, A>>::Type
+    where
+        A: 'static,
+    {
+        Kleisli::new(<TCon as Lift>::lift)
+    }
+
+    fn compose<A, B, C>(
+        f: <TypeCon<TCon> as WithTypeArgs2<B, C>>::Type,
+        g: <TypeCon<TCon> as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon<TCon> as WithTypeArgs2<A, C>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        Kleisli::new(move |x| <TCon as LinearMonadMut>::lbind_mut(g.run(x), |y| f.run(y)))
+    }
+}
+
+impl<TCon> Arrow for TypeCon<TCon>
+where
+    TCon: gat::HigherKinded + LinearFunctor + LinearMonadMut + 'static,
+{
+    fn arr<A, B, F>(f: F) -> <TypeCon<TCon> as WithTypeArgs2<A, B>>::Type
+    where
+        F: Fn(A) -> B + 'static,
+        A: 'static,
+        B: 'static,
+    {
+        Kleisli::new(move |x| <TCon as Lift>::lift(f(x)))
+    }
+}
+
+impl<TCon> ArrowChoice for TypeCon<TCon> where
+    TCon: gat::HigherKinded + LinearFunctor + LinearMonadMut + 'static
+{
+}
@@ -1,7 +1,86 @@
 mod option;
 mod vec;
+mod vec_deque;
+mod linked_list;
 mod result;
+mod boxed;
+mod rc;
+mod arc;
+mod array;
+mod btree_map;
+mod hash_map;
+mod btree_set;
+mod hash_set;
+mod zip_vec;
+mod non_empty;
+mod identity;
+mod env;
+mod store;
+mod traced;
+mod tree;
+mod bin_tree;
+mod predicate;
+mod comparison;
+mod equivalence;
+mod op;
+mod func;
+mod kleisli;
+mod forget;
+mod tagged;
+mod vec_zipper;
+mod tree_zipper;
+mod free;
+mod free_ap;
 
 pub use option::TypeCon as OptionTypeCon;
 pub use vec::TypeCon as VecTypeCon;
+pub use vec_deque::TypeCon as VecDequeTypeCon;
+pub use linked_list::TypeCon as LinkedListTypeCon;
 pub use result::TypeCon as ResultTypeCon;
+pub use boxed::TypeCon as BoxTypeCon;
+pub use rc::TypeCon as RcTypeCon;
+pub use arc::TypeCon as ArcTypeCon;
+pub use array::TypeCon as ArrayTypeCon;
+pub use btree_map::TypeCon as BTreeMapTypeCon;
+pub use hash_map::TypeCon as HashMapTypeCon;
+pub use btree_set::TypeCon as BTreeSetTypeCon;
+pub use hash_set::TypeCon as HashSetTypeCon;
+pub use zip_vec::TypeCon as ZipVecTypeCon;
+pub use zip_vec::FiniteTypeCon as FiniteZipVecTypeCon;
+pub use zip_vec::{FiniteZipVec, ZipVec};
+pub use non_empty::TypeCon as NonEmptyTypeCon;
+pub use non_empty::NonEmpty;
+pub use identity::TypeCon as IdentityTypeCon;
+pub use identity::Identity;
+pub use env::TypeCon as EnvTypeCon;
+pub use store::TypeCon as StoreTypeCon;
+pub use store::Store;
+pub use traced::TypeCon as TracedTypeCon;
+pub use traced::Traced;
+pub use tree::TypeCon as TreeTypeCon;
+pub use tree::{unfold_tree, Tree};
+pub use bin_tree::TypeCon as BinTreeTypeCon;
+pub use bin_tree::{unfold_bin_tree, BinTree};
+pub use vec_zipper::TypeCon as VecZipperTypeCon;
+pub use vec_zipper::VecZipper;
+pub use tree_zipper::TypeCon as TreeZipperTypeCon;
+pub use tree_zipper::TreeZipper;
+pub use predicate::TypeCon as PredicateTypeCon;
+pub use predicate::Predicate;
+pub use comparison::TypeCon as ComparisonTypeCon;
+pub use comparison::Comparison;
+pub use equivalence::TypeCon as EquivalenceTypeCon;
+pub use equivalence::Equivalence;
+pub use op::TypeCon as OpTypeCon;
+pub use op::Op;
+pub use func::TypeCon as FuncTypeCon;
+pub use func::Func;
+pub use kleisli::TypeCon as KleisliTypeCon;
+pub use kleisli::Kleisli;
+pub use forget::TypeCon as ForgetTypeCon;
+pub use forget::Forget;
+pub use tagged::TypeCon as TaggedTypeCon;
+pub use tagged::Tagged;
+pub use free::TypeCon as FreeTypeCon;
+pub use free::{fold_free, lift_f, lift_f_static, Free, NaturalTransformation, StaticFunctor};
+pub use free_ap::{lift_ap, lift_ap_static, run_ap, run_ap_monoid, Answer, FreeAp};
@@ -0,0 +1,284 @@
+use super::vec::TypeCon as VecTypeCon;
+use crate::traversable::sequence_iter;
+use crate::*;
+
+// A Vec which always has at least one element.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonEmpty<T> {
+    pub head: T,
+    pub tail: Vec<T>,
+}
+
+impl<T> NonEmpty<T> {
+    pub fn new(head: T, tail: Vec<T>) -> Self {
+        NonEmpty { head, tail }
+    }
+
+    pub fn singleton(head: T) -> Self {
+        NonEmpty::new(head, Vec::new())
+    }
+
+    // Returns None if the Vec is empty
+    pub fn from_vec(x: Vec<T>) -> Option<Self> {
+        let mut iter = x.into_iter();
+        let head = iter.next()?;
+        Some(NonEmpty::new(head, iter.collect()))
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        let mut result = Vec::with_capacity(self.len());
+        result.push(self.head);
+        result.extend(self.tail);
+        result
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        std::iter::once(&self.head).chain(self.tail.iter())
+    }
+}
+
+impl<T> IntoIterator for NonEmpty<T> {
+    type Item = T;
+    type IntoIter = std::iter::Chain<std::iter::Once<T>, std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(self.head).chain(self.tail)
+    }
+}
+
+impl<T> Semigroup for NonEmpty<T> {
+    fn semigroup_op(mut self, other: Self) -> Self {
+        self.tail.reserve(other.len());
+        self.tail.push(other.head);
+        self.tail.extend(other.tail);
+        self
+    }
+}
+
+// For results which can't be empty, as they were built from non empty inputs.
+fn collect_non_empty<T>(x: impl IntoIterator<Item = T>) -> NonEmpty<T> {
+    let mut iter = x.into_iter();
+    let head = iter
+        .next()
+        .expect("NonEmpty operation produced no elements");
+    NonEmpty::new(head, iter.collect())
+}
+
+type_con!(pub struct TypeCon for<T> NonEmpty<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        NonEmpty::new(f(&x.head), x.tail.iter().map(f).collect())
+    }
+}
+
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        NonEmpty::new(f(x.head), x.tail.into_iter().map(f).collect())
+    }
+}
+
+impl Lift for TypeCon {
+    fn lift<T>(x: T) -> <TypeCon as WithTypeArg<T>>::Type {
+        NonEmpty::singleton(x)
+    }
+}
+
+impl Applicative for TypeCon {
+    fn lift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        let f = &f;
+        collect_non_empty(
+            x1.iter()
+                .flat_map(|x1_val| x2.iter().map(move |x2_val| f(x1_val, x2_val))),
+        )
+    }
+}
+
+impl Monad for TypeCon {
+    fn bind<TIn, TOut, F>(
+        x: &<Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> <Self as WithTypeArg<TOut>>::Type,
+    {
+        collect_non_empty(x.iter().flat_map(f))
+    }
+}
+
+// These go via the Vec instances, as the results of combining non empty values are non empty.
+impl LinearApplicativeMut for TypeCon {
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <TypeCon as WithTypeArg<TFunc>>::Type,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn) -> TOut,
+        TIn: Clone,
+    {
+        collect_non_empty(<VecTypeCon as LinearApplicativeMut>::lap_mut(
+            f.into_vec(),
+            x.into_vec(),
+        ))
+    }
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        collect_non_empty(<VecTypeCon as LinearApplicativeMut>::llift2_mut(
+            f,
+            x1.into_vec(),
+            x2.into_vec(),
+        ))
+    }
+}
+
+impl LinearMonadMut for TypeCon {
+    fn lbind_mut<TIn, TOut, F>(
+        x: <Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: FnMut(TIn) -> <Self as WithTypeArg<TOut>>::Type,
+    {
+        collect_non_empty(x.into_iter().flat_map(f))
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_iter()
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.iter().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Foldable1 for TypeCon {
+    fn fold1<T, F>(f: F, x: <Self as WithTypeArg<T>>::Type) -> T
+    where
+        F: Fn(T, T) -> T,
+    {
+        let mut iter = x.into_iter().rev();
+        let last = iter.next().unwrap();
+        iter.fold(last, |acc, next_val| f(next_val, acc))
+    }
+
+    fn head<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T {
+        &x.head
+    }
+
+    fn last<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T {
+        x.tail.last().unwrap_or(&x.head)
+    }
+
+    fn maximum1<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T
+    where
+        T: Ord,
+    {
+        x.iter().max().unwrap()
+    }
+
+    fn minimum1<T>(x: &<Self as WithTypeArg<T>>::Type) -> &T
+    where
+        T: Ord,
+    {
+        x.iter().min().unwrap()
+    }
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        <TApplicative as LinearApplicative>::llift2(
+            NonEmpty::new,
+            x.head,
+            sequence_iter::<TApplicative, T, _>(x.tail),
+        )
+    }
+}
+
+// The context of each element is the suffix starting at it.
+impl Comonad for TypeCon {
+    fn extract<T>(x: &<TypeCon as WithTypeArg<T>>::Type) -> T
+    where
+        T: Clone,
+    {
+        x.head.clone()
+    }
+
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&<TypeCon as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        collect_non_empty((0..x.len()).map(|i| f(&collect_non_empty(x.iter().skip(i).cloned()))))
+    }
+}
+
+impl LinearComonad for TypeCon {
+    fn lextract<T>(x: <TypeCon as WithTypeArg<T>>::Type) -> T {
+        x.head
+    }
+
+    fn lduplicate<T>(
+        x: <TypeCon as WithTypeArg<T>>::Type,
+    ) -> <TypeCon as WithTypeArg<<TypeCon as WithTypeArg<T>>::Type>>::Type
+    where
+        T: Clone,
+    {
+        let suffixes = (1..x.len())
+            .map(|i| collect_non_empty(x.tail[i - 1..].iter().cloned()))
+            .collect();
+        NonEmpty::new(x, suffixes)
+    }
+}
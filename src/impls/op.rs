@@ -0,0 +1,70 @@
+use crate::*;
+
+// A function with its argument type last, so it can be Contravariant in it. It's Divisible when
+// its results can be combined, with `semigroup_op`.
+pub struct Op<R, A>(pub Box<dyn Fn(&A) -> R>);
+
+impl<R, A> Op<R, A> {
+    pub fn new<F: Fn(&A) -> R + 'static>(f: F) -> Self {
+        Op(Box::new(f))
+    }
+
+    pub fn run(&self, x: &A) -> R {
+        (self.0)(x)
+    }
+}
+
+type_con!(pub struct TypeCon<R> for<A> Op<R, A>);
+
+impl<R: 'static> Contravariant for TypeCon<R> {
+    fn contramap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon<R> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<R> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> TIn + 'static,
+        TIn: 'static,
+    {
+        Op::new(move |y| x.run(&f(y)))
+    }
+}
+
+impl<R: Monoid + 'static> Divisible for TypeCon<R> {
+    fn divide<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon<R> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<R> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<R> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> (TIn1, TIn2) + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Op::new(move |y| {
+            let (y1, y2) = f(y);
+            x1.run(&y1).semigroup_op(x2.run(&y2))
+        })
+    }
+
+    fn conquer<T>() -> <TypeCon<R> as WithTypeArg<T>>::Type {
+        Op::new(|_| R::default())
+    }
+}
+
+impl<R: Monoid + 'static> Decidable for TypeCon<R> {
+    fn choose<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon<R> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<R> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<R> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> Either<TIn1, TIn2> + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Op::new(move |y| match f(y) {
+            Either::Left(y1) => x1.run(&y1),
+            Either::Right(y2) => x2.run(&y2),
+        })
+    }
+}
@@ -1,16 +1,6 @@
 use crate::*;
 
-pub struct TypeCon;
-
-impl<T> TypeAppParam for Option<T> {
-    type Param = T;
-}
-
-impl<T> TypeApp<TypeCon, T> for Option<T> {}
-
-impl<T> WithTypeArg<T> for TypeCon {
-    type Type = Option<T>;
-}
+type_con!(pub struct TypeCon for<T> Option<T>);
 
 impl Functor for TypeCon {
     fn fmap<TIn, TOut, F>(
@@ -64,6 +54,107 @@ impl Applicative for TypeCon {
         x1.as_ref()
             .and_then(|x1_val| x2.as_ref().map(|x2_val| f(x1_val, x2_val)))
     }
+
+    fn seq_left<TIn1, TIn2>(
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TIn1>>::Type
+    where
+        TIn1: Clone,
+    {
+        x2.as_ref().and(x1.clone())
+    }
+
+    fn seq_right<TIn1, TIn2>(
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TIn2>>::Type
+    where
+        TIn2: Clone,
+    {
+        x1.as_ref().and(x2.clone())
+    }
+
+    fn zip_a<TIn1, TIn2>(
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<(TIn1, TIn2)>>::Type
+    where
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        x1.clone().zip(x2.clone())
+    }
+}
+
+impl ApplicativeN for TypeCon {
+    fn lift3<TIn1, TIn2, TIn3, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3) -> TOut,
+    {
+        match (x1, x2, x3) {
+            (Some(y1), Some(y2), Some(y3)) => Some(f(y1, y2, y3)),
+            _ => None,
+        }
+    }
+
+    fn lift4<TIn1, TIn2, TIn3, TIn4, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon as WithTypeArg<TIn4>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4) -> TOut,
+    {
+        match (x1, x2, x3, x4) {
+            (Some(y1), Some(y2), Some(y3), Some(y4)) => Some(f(y1, y2, y3, y4)),
+            _ => None,
+        }
+    }
+
+    fn lift5<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon as WithTypeArg<TIn4>>::Type,
+        x5: &<TypeCon as WithTypeArg<TIn5>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5) {
+            (Some(y1), Some(y2), Some(y3), Some(y4), Some(y5)) => Some(f(y1, y2, y3, y4, y5)),
+            _ => None,
+        }
+    }
+
+    fn lift6<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon as WithTypeArg<TIn4>>::Type,
+        x5: &<TypeCon as WithTypeArg<TIn5>>::Type,
+        x6: &<TypeCon as WithTypeArg<TIn6>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5, &TIn6) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5, x6) {
+            (Some(y1), Some(y2), Some(y3), Some(y4), Some(y5), Some(y6)) => {
+                Some(f(y1, y2, y3, y4, y5, y6))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl LinearApplicative for TypeCon {
@@ -74,7 +165,7 @@ impl LinearApplicative for TypeCon {
     where
         TFunc: FnOnce(TIn) -> TOut,
     {
-        f.and_then(|f_val| x.map(|x_val| f_val(x_val)))
+        f.and_then(|f_val| x.map(f_val))
     }
 
     fn llift2<TIn1, TIn2, TOut, TFunc>(
@@ -87,6 +178,95 @@ impl LinearApplicative for TypeCon {
     {
         x1.and_then(|x1val| x2.map(|x2val| f(x1val, x2val)))
     }
+
+    fn llift3<TIn1, TIn2, TIn3, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon as WithTypeArg<TIn3>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3) -> TOut,
+    {
+        match (x1, x2, x3) {
+            (Some(y1), Some(y2), Some(y3)) => Some(f(y1, y2, y3)),
+            _ => None,
+        }
+    }
+
+    fn llift4<TIn1, TIn2, TIn3, TIn4, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: <TypeCon as WithTypeArg<TIn4>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4) -> TOut,
+    {
+        match (x1, x2, x3, x4) {
+            (Some(y1), Some(y2), Some(y3), Some(y4)) => Some(f(y1, y2, y3, y4)),
+            _ => None,
+        }
+    }
+
+    fn llift5<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: <TypeCon as WithTypeArg<TIn4>>::Type,
+        x5: <TypeCon as WithTypeArg<TIn5>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5) {
+            (Some(y1), Some(y2), Some(y3), Some(y4), Some(y5)) => Some(f(y1, y2, y3, y4, y5)),
+            _ => None,
+        }
+    }
+
+    fn llift6<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: <TypeCon as WithTypeArg<TIn4>>::Type,
+        x5: <TypeCon as WithTypeArg<TIn5>>::Type,
+        x6: <TypeCon as WithTypeArg<TIn6>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5, TIn6) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5, x6) {
+            (Some(y1), Some(y2), Some(y3), Some(y4), Some(y5), Some(y6)) => {
+                Some(f(y1, y2, y3, y4, y5, y6))
+            }
+            _ => None,
+        }
+    }
+
+    fn lseq_left<TIn1, TIn2>(
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TIn1>>::Type {
+        x2.and(x1)
+    }
+
+    fn lseq_right<TIn1, TIn2>(
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TIn2>>::Type {
+        x1.and(x2)
+    }
+
+    fn lzip_a<TIn1, TIn2>(
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<(TIn1, TIn2)>>::Type {
+        x1.zip(x2)
+    }
 }
 
 impl Monad for TypeCon {
@@ -127,6 +307,22 @@ impl Foldable for TypeCon {
     where
         F: Fn(&TIn, TOut) -> TOut,
     {
-        x.into_iter().fold(init, |acc, next_val| f(next_val, acc))
+        x.iter().fold(init, |acc, next_val| f(next_val, acc))
     }
-}
\ No newline at end of file
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        match x {
+            None => <TApplicative as Lift>::lift(None),
+            Some(y) => {
+                <TApplicative as LinearApplicative>::lap(<TApplicative as Lift>::lift(Some), y)
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::*;
+use std::convert::Infallible;
+
+pub struct Predicate<A>(pub Box<dyn Fn(&A) -> bool>);
+
+impl<A> Predicate<A> {
+    pub fn new<F: Fn(&A) -> bool + 'static>(f: F) -> Self {
+        Predicate(Box::new(f))
+    }
+
+    pub fn test(&self, x: &A) -> bool {
+        (self.0)(x)
+    }
+}
+
+type_con!(pub struct TypeCon for<A> Predicate<A>);
+
+impl Contravariant for TypeCon {
+    fn contramap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> TIn + 'static,
+        TIn: 'static,
+    {
+        Predicate::new(move |y| x.test(&f(y)))
+    }
+}
+
+// Both parts have to pass
+impl Divisible for TypeCon {
+    fn divide<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> (TIn1, TIn2) + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Predicate::new(move |y| {
+            let (y1, y2) = f(y);
+            x1.test(&y1) && x2.test(&y2)
+        })
+    }
+
+    fn conquer<T>() -> <TypeCon as WithTypeArg<T>>::Type {
+        Predicate::new(|_| true)
+    }
+}
+
+impl Decidable for TypeCon {
+    fn choose<TIn1, TIn2, TOut, F>(
+        f: F,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TOut) -> Either<TIn1, TIn2> + 'static,
+        TIn1: 'static,
+        TIn2: 'static,
+    {
+        Predicate::new(move |y| match f(y) {
+            Either::Left(y1) => x1.test(&y1),
+            Either::Right(y2) => x2.test(&y2),
+        })
+    }
+
+    fn lose<T, F>(f: F) -> <TypeCon as WithTypeArg<T>>::Type
+    where
+        F: Fn(&T) -> Infallible + 'static,
+    {
+        Predicate::new(move |y| match f(y) {})
+    }
+}
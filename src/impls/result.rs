@@ -1,18 +1,6 @@
 use crate::*;
 
-pub struct TypeCon<E> {
-    _unused: std::marker::PhantomData<E>,
-}
-
-impl<T, E> TypeAppParam for Result<T, E> {
-    type Param = T;
-}
-
-impl<T, E> TypeApp<TypeCon<E>, T> for Result<T, E> {}
-
-impl<T, E> WithTypeArg<T> for TypeCon<E> {
-    type Type = Result<T, E>;
-}
+type_con!(pub struct TypeCon<E> for<T> Result<T, E>);
 
 impl<E> LinearFunctor for TypeCon<E> {
     fn lmap<TIn, TOut, F>(
@@ -55,7 +43,7 @@ impl<E> LinearApplicative for TypeCon<E> {
     where
         TFunc: FnOnce(TIn) -> TOut,
     {
-        f.and_then(|f_val| x.map(|x_val| f_val(x_val)))
+        f.and_then(|f_val| x.map(f_val))
     }
 
     fn llift2<TIn1, TIn2, TOut, TFunc>(
@@ -68,6 +56,102 @@ impl<E> LinearApplicative for TypeCon<E> {
     {
         x1.and_then(|x1val| x2.map(|x2val| f(x1val, x2val)))
     }
+
+    fn llift3<TIn1, TIn2, TIn3, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon<E> as WithTypeArg<TIn3>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3) -> TOut,
+    {
+        match (x1, x2, x3) {
+            (Ok(y1), Ok(y2), Ok(y3)) => Ok(f(y1, y2, y3)),
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => Err(e),
+        }
+    }
+
+    fn llift4<TIn1, TIn2, TIn3, TIn4, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon<E> as WithTypeArg<TIn3>>::Type,
+        x4: <TypeCon<E> as WithTypeArg<TIn4>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4) -> TOut,
+    {
+        match (x1, x2, x3, x4) {
+            (Ok(y1), Ok(y2), Ok(y3), Ok(y4)) => Ok(f(y1, y2, y3, y4)),
+            (Err(e), _, _, _) | (_, Err(e), _, _) | (_, _, Err(e), _) | (_, _, _, Err(e)) => Err(e),
+        }
+    }
+
+    fn llift5<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon<E> as WithTypeArg<TIn3>>::Type,
+        x4: <TypeCon<E> as WithTypeArg<TIn4>>::Type,
+        x5: <TypeCon<E> as WithTypeArg<TIn5>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5) {
+            (Ok(y1), Ok(y2), Ok(y3), Ok(y4), Ok(y5)) => Ok(f(y1, y2, y3, y4, y5)),
+            (Err(e), _, _, _, _)
+            | (_, Err(e), _, _, _)
+            | (_, _, Err(e), _, _)
+            | (_, _, _, Err(e), _)
+            | (_, _, _, _, Err(e)) => Err(e),
+        }
+    }
+
+    fn llift6<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, TFunc>(
+        f: TFunc,
+        x1: <TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: <TypeCon<E> as WithTypeArg<TIn3>>::Type,
+        x4: <TypeCon<E> as WithTypeArg<TIn4>>::Type,
+        x5: <TypeCon<E> as WithTypeArg<TIn5>>::Type,
+        x6: <TypeCon<E> as WithTypeArg<TIn6>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2, TIn3, TIn4, TIn5, TIn6) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5, x6) {
+            (Ok(y1), Ok(y2), Ok(y3), Ok(y4), Ok(y5), Ok(y6)) => Ok(f(y1, y2, y3, y4, y5, y6)),
+            (Err(e), _, _, _, _, _)
+            | (_, Err(e), _, _, _, _)
+            | (_, _, Err(e), _, _, _)
+            | (_, _, _, Err(e), _, _)
+            | (_, _, _, _, Err(e), _)
+            | (_, _, _, _, _, Err(e)) => Err(e),
+        }
+    }
+
+    fn lseq_left<TIn1, TIn2>(
+        x1: <TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<E> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TIn1>>::Type {
+        x1.and_then(|y1| x2.map(|_| y1))
+    }
+
+    fn lseq_right<TIn1, TIn2>(
+        x1: <TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<E> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TIn2>>::Type {
+        x1.and(x2)
+    }
+
+    fn lzip_a<TIn1, TIn2>(
+        x1: <TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon<E> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<(TIn1, TIn2)>>::Type {
+        x1.and_then(|y1| x2.map(|y2| (y1, y2)))
+    }
 }
 
 impl<E> Applicative for TypeCon<E>
@@ -98,6 +182,128 @@ where
             .and_then(|x1_val| x2.as_ref().map(|x2_val| f(x1_val, x2_val)))
             .clone_err()
     }
+
+    fn seq_left<TIn1, TIn2>(
+        x1: &<TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<E> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TIn1>>::Type
+    where
+        TIn1: Clone,
+    {
+        match (x1, x2) {
+            (Ok(y1), Ok(_)) => Ok(y1.clone()),
+            (Err(e), _) | (_, Err(e)) => Err(e.clone()),
+        }
+    }
+
+    fn seq_right<TIn1, TIn2>(
+        x1: &<TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<E> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TIn2>>::Type
+    where
+        TIn2: Clone,
+    {
+        match (x1, x2) {
+            (Ok(_), Ok(y2)) => Ok(y2.clone()),
+            (Err(e), _) | (_, Err(e)) => Err(e.clone()),
+        }
+    }
+
+    fn zip_a<TIn1, TIn2>(
+        x1: &<TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<E> as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<(TIn1, TIn2)>>::Type
+    where
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        match (x1, x2) {
+            (Ok(y1), Ok(y2)) => Ok((y1.clone(), y2.clone())),
+            (Err(e), _) | (_, Err(e)) => Err(e.clone()),
+        }
+    }
+}
+
+impl<E> ApplicativeN for TypeCon<E>
+where
+    E: Clone,
+{
+    fn lift3<TIn1, TIn2, TIn3, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon<E> as WithTypeArg<TIn3>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3) -> TOut,
+    {
+        match (x1, x2, x3) {
+            (Ok(y1), Ok(y2), Ok(y3)) => Ok(f(y1, y2, y3)),
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => Err(e.clone()),
+        }
+    }
+
+    fn lift4<TIn1, TIn2, TIn3, TIn4, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon<E> as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon<E> as WithTypeArg<TIn4>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4) -> TOut,
+    {
+        match (x1, x2, x3, x4) {
+            (Ok(y1), Ok(y2), Ok(y3), Ok(y4)) => Ok(f(y1, y2, y3, y4)),
+            (Err(e), _, _, _) | (_, Err(e), _, _) | (_, _, Err(e), _) | (_, _, _, Err(e)) => {
+                Err(e.clone())
+            }
+        }
+    }
+
+    fn lift5<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon<E> as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon<E> as WithTypeArg<TIn4>>::Type,
+        x5: &<TypeCon<E> as WithTypeArg<TIn5>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5) {
+            (Ok(y1), Ok(y2), Ok(y3), Ok(y4), Ok(y5)) => Ok(f(y1, y2, y3, y4, y5)),
+            (Err(e), _, _, _, _)
+            | (_, Err(e), _, _, _)
+            | (_, _, Err(e), _, _)
+            | (_, _, _, Err(e), _)
+            | (_, _, _, _, Err(e)) => Err(e.clone()),
+        }
+    }
+
+    fn lift6<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon<E> as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon<E> as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon<E> as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon<E> as WithTypeArg<TIn4>>::Type,
+        x5: &<TypeCon<E> as WithTypeArg<TIn5>>::Type,
+        x6: &<TypeCon<E> as WithTypeArg<TIn6>>::Type,
+    ) -> <TypeCon<E> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5, &TIn6) -> TOut,
+    {
+        match (x1, x2, x3, x4, x5, x6) {
+            (Ok(y1), Ok(y2), Ok(y3), Ok(y4), Ok(y5), Ok(y6)) => Ok(f(y1, y2, y3, y4, y5, y6)),
+            (Err(e), _, _, _, _, _)
+            | (_, Err(e), _, _, _, _)
+            | (_, _, Err(e), _, _, _)
+            | (_, _, _, Err(e), _, _)
+            | (_, _, _, _, Err(e), _)
+            | (_, _, _, _, _, Err(e)) => Err(e.clone()),
+        }
+    }
 }
 
 impl<E> LinearMonad for TypeCon<E> {
@@ -142,3 +348,35 @@ where
         self.map_err(|err| Clone::clone(err))
     }
 }
+
+impl<E> LinearFoldable for TypeCon<E> {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_iter().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<E> Foldable for TypeCon<E> {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.iter().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl<E> LinearTraversable for TypeCon<E> {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        match x {
+            Ok(y) => <TApplicative as LinearApplicative>::lap(<TApplicative as Lift>::lift(Ok), y),
+            Err(e) => <TApplicative as Lift>::lift(Err(e)),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+// The store comonad, a value at every position `S`, focused on one of them.
+//
+// In Haskell this is a function `S -> A` plus the current position. That can't work here, as
+// `fmap` would have to keep its function inside the result, and `Functor` doesn't require
+// functions to be `'static`. So instead the store is tabulated, holding its values for a finite
+// set of positions. The store owns its table, so `lmap` can move the values out of it.
+//
+// This suits grids and other finite spaces, where `peek` returns `None` off the edge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Store<S, A> {
+    table: BTreeMap<S, A>,
+    pos: S,
+}
+
+impl<S: Ord, A> Store<S, A> {
+    // Returns None if `pos` isn't in the table
+    pub fn new(table: BTreeMap<S, A>, pos: S) -> Option<Self> {
+        if table.contains_key(&pos) {
+            Some(Store { table, pos })
+        } else {
+            None
+        }
+    }
+
+    pub fn tabulate<I, F>(positions: I, f: F, pos: S) -> Option<Self>
+    where
+        I: IntoIterator<Item = S>,
+        F: Fn(&S) -> A,
+    {
+        let table = positions
+            .into_iter()
+            .map(|s| {
+                let a = f(&s);
+                (s, a)
+            })
+            .collect();
+        Store::new(table, pos)
+    }
+
+    pub fn pos(&self) -> &S {
+        &self.pos
+    }
+
+    pub fn peek(&self, s: &S) -> Option<&A> {
+        self.table.get(s)
+    }
+
+    // Moves the focus, copying the table. Returns None if `pos` isn't in the table.
+    pub fn seek(&self, pos: S) -> Option<Self>
+    where
+        S: Clone,
+        A: Clone,
+    {
+        if self.table.contains_key(&pos) {
+            Some(Store {
+                table: self.table.clone(),
+                pos,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = &S> {
+        self.table.keys()
+    }
+}
+
+type_con!(pub struct TypeCon<S> for<A> Store<S, A>);
+
+impl<S: Ord + Clone> Functor for TypeCon<S> {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon<S> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<S> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        Store {
+            table: x.table.iter().map(|(s, a)| (s.clone(), f(a))).collect(),
+            pos: x.pos.clone(),
+        }
+    }
+}
+
+impl<S: Ord> LinearFunctor for TypeCon<S> {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon<S> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<S> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        Store {
+            table: x.table.into_iter().map(|(s, a)| (s, f(a))).collect(),
+            pos: x.pos,
+        }
+    }
+}
+
+impl<S: Ord + Clone> Comonad for TypeCon<S> {
+    fn extract<T>(x: &<TypeCon<S> as WithTypeArg<T>>::Type) -> T
+    where
+        T: Clone,
+    {
+        x.table[&x.pos].clone()
+    }
+
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon<S> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<S> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&<TypeCon<S> as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        // One copy of the store is moved to each position in turn.
+        let mut focused = x.clone();
+        let table = x
+            .table
+            .keys()
+            .map(|s| {
+                focused.pos = s.clone();
+                (s.clone(), f(&focused))
+            })
+            .collect();
+        Store {
+            table,
+            pos: x.pos.clone(),
+        }
+    }
+}
+
+impl<S: Ord + Clone> LinearComonad for TypeCon<S> {
+    fn lextract<T>(mut x: <TypeCon<S> as WithTypeArg<T>>::Type) -> T {
+        x.table.remove(&x.pos).unwrap()
+    }
+
+    fn lduplicate<T>(
+        x: <TypeCon<S> as WithTypeArg<T>>::Type,
+    ) -> <TypeCon<S> as WithTypeArg<<TypeCon<S> as WithTypeArg<T>>::Type>>::Type
+    where
+        T: Clone,
+    {
+        let table = x
+            .table
+            .keys()
+            .map(|s| {
+                let focused = Store {
+                    table: x.table.clone(),
+                    pos: s.clone(),
+                };
+                (s.clone(), focused)
+            })
+            .collect();
+        Store { table, pos: x.pos }
+    }
+}
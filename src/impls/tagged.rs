@@ -0,0 +1,46 @@
+use crate::*;
+use std::marker::PhantomData;
+
+// A value of type `B`, ignoring the profunctor's input type, so `dimap` only uses its second
+// function. Optics use it to build structures from a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tagged<A, B>(pub B, PhantomData<fn(A)>);
+
+impl<A, B> Tagged<A, B> {
+    pub fn new(x: B) -> Self {
+        Tagged(x, PhantomData)
+    }
+}
+
+type_con!(pub struct TypeCon for<A, B> Tagged<A, B>);
+
+impl Profunctor for TypeCon {
+    fn dimap<A, B, C, D, F, G>(
+        _f: F,
+        g: G,
+        x: <TypeCon as WithTypeArgs2<B, C>>::Type,
+    ) -> <TypeCon as WithTypeArgs2<A, D>>::Type
+    where
+        F: Fn(A) -> B + 'static,
+        G: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        Tagged::new(g(x.0))
+    }
+}
+
+impl Choice for TypeCon {
+    fn left<A, B, C>(
+        x: <TypeCon as WithTypeArgs2<A, B>>::Type,
+    ) -> <TypeCon as WithTypeArgs2<Either<A, C>, Either<B, C>>>::Type
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        Tagged::new(Either::Left(x.0))
+    }
+}
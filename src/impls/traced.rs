@@ -0,0 +1,126 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+// The traced comonad, a value for every element of a monoid `M`, read relative to the current
+// offset. `trace(m)` looks at the offset combined with `m`, and `extract` is `trace` of the
+// identity element.
+//
+// Like `Store`, this is tabulated rather than holding a function, so it only covers the elements
+// of `M` in its table. For `trace` to be total the table's keys should be closed under
+// `semigroup_op`, like the elements of a cyclic group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Traced<M, A> {
+    table: BTreeMap<M, A>,
+    offset: M,
+}
+
+impl<M: Monoid + Ord + Clone, A> Traced<M, A> {
+    // Returns None if the identity element isn't in the table
+    pub fn new(table: BTreeMap<M, A>) -> Option<Self> {
+        let offset = M::default();
+        if table.contains_key(&offset) {
+            Some(Traced { table, offset })
+        } else {
+            None
+        }
+    }
+
+    pub fn trace(&self, m: M) -> Option<&A> {
+        self.table.get(&self.offset.clone().semigroup_op(m))
+    }
+}
+
+type_con!(pub struct TypeCon<M> for<A> Traced<M, A>);
+
+impl<M: Ord + Clone> Functor for TypeCon<M> {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon<M> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<M> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        Traced {
+            table: x.table.iter().map(|(m, a)| (m.clone(), f(a))).collect(),
+            offset: x.offset.clone(),
+        }
+    }
+}
+
+impl<M: Ord> LinearFunctor for TypeCon<M> {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon<M> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<M> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        Traced {
+            table: x.table.into_iter().map(|(m, a)| (m, f(a))).collect(),
+            offset: x.offset,
+        }
+    }
+}
+
+impl<M: Ord + Clone> Comonad for TypeCon<M> {
+    fn extract<T>(x: &<TypeCon<M> as WithTypeArg<T>>::Type) -> T
+    where
+        T: Clone,
+    {
+        x.table[&x.offset].clone()
+    }
+
+    // The result at `m` sees the trace shifted by `m`.
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon<M> as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon<M> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&<TypeCon<M> as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        // One copy of the trace is shifted by each element in turn.
+        let mut shifted = x.clone();
+        let table = x
+            .table
+            .keys()
+            .map(|m| {
+                shifted.offset = m.clone();
+                (m.clone(), f(&shifted))
+            })
+            .collect();
+        Traced {
+            table,
+            offset: x.offset.clone(),
+        }
+    }
+}
+
+impl<M: Ord + Clone> LinearComonad for TypeCon<M> {
+    fn lextract<T>(mut x: <TypeCon<M> as WithTypeArg<T>>::Type) -> T {
+        x.table.remove(&x.offset).unwrap()
+    }
+
+    fn lduplicate<T>(
+        x: <TypeCon<M> as WithTypeArg<T>>::Type,
+    ) -> <TypeCon<M> as WithTypeArg<<TypeCon<M> as WithTypeArg<T>>::Type>>::Type
+    where
+        T: Clone,
+    {
+        let table = x
+            .table
+            .keys()
+            .map(|m| {
+                let shifted = Traced {
+                    table: x.table.clone(),
+                    offset: m.clone(),
+                };
+                (m.clone(), shifted)
+            })
+            .collect();
+        Traced {
+            table,
+            offset: x.offset,
+        }
+    }
+}
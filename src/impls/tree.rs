@@ -0,0 +1,362 @@
+use crate::traversable::sequence_iter;
+use crate::*;
+
+// A rose tree, a value with any number of children.
+//
+// Its Foldable and Traversable instances visit values in pre-order, as Haskell's Data.Tree does.
+// The `_postorder` fold and traversal give the other order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tree<T> {
+    pub value: T,
+    pub children: Vec<Tree<T>>,
+}
+
+impl<T> Tree<T> {
+    pub fn new(value: T, children: Vec<Tree<T>>) -> Self {
+        Tree { value, children }
+    }
+
+    pub fn leaf(value: T) -> Self {
+        Tree::new(value, Vec::new())
+    }
+
+    // The iterators are lazy, keeping a stack of the children still to visit.
+    pub fn preorder(&self) -> impl Iterator<Item = &T> {
+        Iter::new(self, Order::Pre)
+    }
+
+    pub fn postorder(&self) -> impl Iterator<Item = &T> {
+        Iter::new(self, Order::Post)
+    }
+
+    // The fold and traversal in post-order, which the instances don't use
+    pub fn foldr_postorder<U, F>(&self, f: F, init: U) -> U
+    where
+        F: Fn(&T, U) -> U,
+    {
+        foldr_refs(Order::Post, &f, self, init)
+    }
+
+    pub fn lfoldr_postorder<U, F>(self, f: F, init: U) -> U
+    where
+        F: Fn(T, U) -> U,
+    {
+        foldr_values(Order::Post, &f, self, init)
+    }
+
+    pub fn traverse_postorder<TApplicative, TOut, F>(
+        self,
+        f: F,
+    ) -> <TApplicative as WithTypeArg<Tree<TOut>>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+        F: Fn(T) -> <TApplicative as WithTypeArg<TOut>>::Type,
+    {
+        sequence_in::<TApplicative, TOut>(Order::Post, lmap_tree(&f, self))
+    }
+
+    // The values in pre-order
+    pub fn flatten(self) -> Vec<T> {
+        let mut result = Vec::new();
+        preorder_values(self, &mut result);
+        result
+    }
+}
+
+// Builds a tree from a seed, where `f` gives the value for each seed and the seeds of its children.
+pub fn unfold_tree<S, T, F>(f: F, seed: S) -> Tree<T>
+where
+    F: Fn(S) -> (T, Vec<S>),
+{
+    fn go<S, T>(f: &impl Fn(S) -> (T, Vec<S>), seed: S) -> Tree<T> {
+        let (value, seeds) = f(seed);
+        Tree::new(value, seeds.into_iter().map(|s| go(f, s)).collect())
+    }
+    go(&f, seed)
+}
+
+pub(super) fn fmap_tree<TIn, TOut, F>(f: &F, x: &Tree<TIn>) -> Tree<TOut>
+where
+    F: Fn(&TIn) -> TOut,
+{
+    Tree::new(
+        f(&x.value),
+        x.children.iter().map(|child| fmap_tree(f, child)).collect(),
+    )
+}
+
+pub(super) fn lmap_tree<TIn, TOut, F>(f: &F, x: Tree<TIn>) -> Tree<TOut>
+where
+    F: Fn(TIn) -> TOut,
+{
+    Tree::new(
+        f(x.value),
+        x.children
+            .into_iter()
+            .map(|child| lmap_tree(f, child))
+            .collect(),
+    )
+}
+
+pub(super) fn preorder_values<T>(x: Tree<T>, result: &mut Vec<T>) {
+    result.push(x.value);
+    for child in x.children {
+        preorder_values(child, result);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Order {
+    Pre,
+    Post,
+}
+
+enum Step<'a, T> {
+    Visit(&'a Tree<T>),
+    Yield(&'a T),
+}
+
+struct Iter<'a, T> {
+    order: Order,
+    stack: Vec<Step<'a, T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn new(x: &'a Tree<T>, order: Order) -> Self {
+        Iter {
+            order,
+            stack: vec![Step::Visit(x)],
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match self.stack.pop()? {
+                Step::Yield(value) => return Some(value),
+                // The children are pushed last first, so the first is on top
+                Step::Visit(x) => match self.order {
+                    Order::Pre => {
+                        self.stack.extend(x.children.iter().rev().map(Step::Visit));
+                        return Some(&x.value);
+                    }
+                    Order::Post => {
+                        self.stack.push(Step::Yield(&x.value));
+                        self.stack.extend(x.children.iter().rev().map(Step::Visit));
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn foldr_refs<T, U>(order: Order, f: &impl Fn(&T, U) -> U, x: &Tree<T>, acc: U) -> U {
+    let fold_children = |acc| {
+        x.children
+            .iter()
+            .rev()
+            .fold(acc, |acc, child| foldr_refs(order, f, child, acc))
+    };
+    match order {
+        Order::Pre => f(&x.value, fold_children(acc)),
+        Order::Post => fold_children(f(&x.value, acc)),
+    }
+}
+
+fn foldr_values<T, U>(order: Order, f: &impl Fn(T, U) -> U, x: Tree<T>, acc: U) -> U {
+    let fold_children = |children: Vec<Tree<T>>, acc| {
+        children
+            .into_iter()
+            .rev()
+            .fold(acc, |acc, child| foldr_values(order, f, child, acc))
+    };
+    match order {
+        Order::Pre => f(x.value, fold_children(x.children, acc)),
+        Order::Post => fold_children(x.children, f(x.value, acc)),
+    }
+}
+
+// Runs the node's effect before or after its children's effects
+fn sequence_in<TApplicative, T>(
+    order: Order,
+    x: Tree<<TApplicative as WithTypeArg<T>>::Type>,
+) -> <TApplicative as WithTypeArg<Tree<T>>>::Type
+where
+    TApplicative: LinearApplicative + gat::HigherKinded,
+{
+    let children = sequence_iter::<TApplicative, _, _>(
+        x.children
+            .into_iter()
+            .map(|child| sequence_in::<TApplicative, T>(order, child)),
+    );
+    match order {
+        Order::Pre => <TApplicative as LinearApplicative>::llift2(Tree::new, x.value, children),
+        Order::Post => <TApplicative as LinearApplicative>::llift2(
+            |children, value| Tree::new(value, children),
+            children,
+            x.value,
+        ),
+    }
+}
+
+type_con!(pub struct TypeCon for<T> Tree<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        fmap_tree(&f, x)
+    }
+}
+
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        lmap_tree(&f, x)
+    }
+}
+
+impl Lift for TypeCon {
+    fn lift<T>(x: T) -> <TypeCon as WithTypeArg<T>>::Type {
+        Tree::leaf(x)
+    }
+}
+
+// The root combines both roots. Its children are the second tree's children combined with the
+// first root, followed by the first tree's children combined with the whole second tree. This
+// agrees with `bind`.
+impl Applicative for TypeCon {
+    fn lift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        fn go<TIn1, TIn2, TOut>(
+            f: &impl Fn(&TIn1, &TIn2) -> TOut,
+            x1: &Tree<TIn1>,
+            x2: &Tree<TIn2>,
+        ) -> Tree<TOut> {
+            let from_x2 = x2
+                .children
+                .iter()
+                .map(|child| fmap_tree(&|y: &TIn2| f(&x1.value, y), child));
+            let from_x1 = x1.children.iter().map(|child| go(f, child, x2));
+            Tree::new(f(&x1.value, &x2.value), from_x2.chain(from_x1).collect())
+        }
+        go(&f, x1, x2)
+    }
+}
+
+// The tree for the root replaces it, and the root's children, bound, follow its children.
+impl Monad for TypeCon {
+    fn bind<TIn, TOut, F>(
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        fn go<TIn, TOut>(f: &impl Fn(&TIn) -> Tree<TOut>, x: &Tree<TIn>) -> Tree<TOut> {
+            let mut result = f(&x.value);
+            result
+                .children
+                .extend(x.children.iter().map(|child| go(f, child)));
+            result
+        }
+        go(&f, x)
+    }
+}
+
+// Like `lift2`, the second tree is copied for each value of the first.
+impl LinearApplicativeMut for TypeCon {
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <TypeCon as WithTypeArg<TFunc>>::Type,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn) -> TOut,
+        TIn: Clone,
+    {
+        <TypeCon as LinearMonadMut>::lbind_mut(f, |mut g: TFunc| {
+            <TypeCon as LinearMonadMut>::lbind_mut(x.clone(), |y| Tree::leaf(g(y)))
+        })
+    }
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        mut f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        <TypeCon as LinearMonadMut>::lbind_mut(x1, |y1: TIn1| {
+            <TypeCon as LinearMonadMut>::lbind_mut(x2.clone(), |y2| Tree::leaf(f(y1.clone(), y2)))
+        })
+    }
+}
+
+impl LinearMonadMut for TypeCon {
+    fn lbind_mut<TIn, TOut, F>(
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+        mut f: F,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: FnMut(TIn) -> <TypeCon as WithTypeArg<TOut>>::Type,
+    {
+        fn go<TIn, TOut>(f: &mut impl FnMut(TIn) -> Tree<TOut>, x: Tree<TIn>) -> Tree<TOut> {
+            let mut result = f(x.value);
+            result
+                .children
+                .extend(x.children.into_iter().map(|child| go(f, child)));
+            result
+        }
+        go(&mut f, x)
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        foldr_values(Order::Pre, &f, x, init)
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        foldr_refs(Order::Pre, &f, x, init)
+    }
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        sequence_in::<TApplicative, T>(Order::Pre, x)
+    }
+}
@@ -0,0 +1,311 @@
+use super::tree::{fmap_tree, lmap_tree, preorder_values, Tree};
+use crate::*;
+
+// A rose tree focused on one of its subtrees, which can be moved around and edited without
+// rebuilding the rest of the tree.
+//
+// Each step down from the root leaves a `Crumb` behind, holding the parent's value and the
+// siblings either side. Like VecZipper, the right siblings are stored in reverse.
+//
+// Folds visit the values of the whole tree in pre-order, wherever the focus is. As a Comonad, the
+// context of each value is the zipper focused on its subtree.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TreeZipper<T> {
+    focus: Tree<T>,
+    parents: Vec<Crumb<T>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Crumb<T> {
+    value: T,
+    left: Vec<Tree<T>>,
+    right: Vec<Tree<T>>,
+}
+
+impl<T> TreeZipper<T> {
+    // Focuses on the root
+    pub fn new(tree: Tree<T>) -> Self {
+        TreeZipper {
+            focus: tree,
+            parents: Vec::new(),
+        }
+    }
+
+    pub fn into_tree(mut self) -> Tree<T> {
+        self.root();
+        self.focus
+    }
+
+    pub fn focus(&self) -> &Tree<T> {
+        &self.focus
+    }
+
+    pub fn value(&self) -> &T {
+        &self.focus.value
+    }
+
+    pub fn depth(&self) -> usize {
+        self.parents.len()
+    }
+
+    // The index of the child taken at each step down from the root to the focus
+    pub fn path(&self) -> Vec<usize> {
+        self.parents.iter().map(|crumb| crumb.left.len()).collect()
+    }
+
+    // Moves the focus to the parent. Returns false, and leaves the zipper alone, at the root.
+    pub fn up(&mut self) -> bool {
+        match self.parents.pop() {
+            Some(Crumb { value, left, right }) => {
+                let child = std::mem::replace(&mut self.focus, Tree::leaf(value));
+                self.focus.children = left;
+                self.focus.children.push(child);
+                self.focus.children.extend(right.into_iter().rev());
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Moves the focus to the child at `index`. Returns false, and leaves the zipper alone, if
+    // there isn't one.
+    pub fn down(&mut self, index: usize) -> bool {
+        if index >= self.focus.children.len() {
+            return false;
+        }
+        let mut left = std::mem::take(&mut self.focus.children);
+        let mut right = left.split_off(index + 1);
+        right.reverse();
+        let child = left.pop().unwrap();
+        let parent = std::mem::replace(&mut self.focus, child);
+        self.parents.push(Crumb {
+            value: parent.value,
+            left,
+            right,
+        });
+        true
+    }
+
+    // Moves the focus to the previous sibling. Returns false, and leaves the zipper alone, if
+    // there isn't one.
+    pub fn left(&mut self) -> bool {
+        match self.parents.last_mut() {
+            Some(crumb) => match crumb.left.pop() {
+                Some(sibling) => {
+                    crumb
+                        .right
+                        .push(std::mem::replace(&mut self.focus, sibling));
+                    true
+                }
+                None => false,
+            },
+            None => false,
+        }
+    }
+
+    // Moves the focus to the next sibling. Returns false, and leaves the zipper alone, if there
+    // isn't one.
+    pub fn right(&mut self) -> bool {
+        match self.parents.last_mut() {
+            Some(crumb) => match crumb.right.pop() {
+                Some(sibling) => {
+                    crumb.left.push(std::mem::replace(&mut self.focus, sibling));
+                    true
+                }
+                None => false,
+            },
+            None => false,
+        }
+    }
+
+    pub fn root(&mut self) {
+        while self.up() {}
+    }
+
+    // Replaces the value at the focus, returning the old one.
+    pub fn set_value(&mut self, x: T) -> T {
+        std::mem::replace(&mut self.focus.value, x)
+    }
+
+    pub fn modify<F: FnOnce(&mut T)>(&mut self, f: F) {
+        f(&mut self.focus.value)
+    }
+
+    // Replaces the subtree at the focus, returning the old one.
+    pub fn set_tree(&mut self, tree: Tree<T>) -> Tree<T> {
+        std::mem::replace(&mut self.focus, tree)
+    }
+
+    // Adds a last child to the focus.
+    pub fn insert_child(&mut self, tree: Tree<T>) {
+        self.focus.children.push(tree);
+    }
+
+    // Removes the subtree at the focus, moving the focus to its parent. Returns None, and leaves
+    // the zipper alone, at the root.
+    pub fn remove(&mut self) -> Option<Tree<T>> {
+        let Crumb {
+            value,
+            mut left,
+            right,
+        } = self.parents.pop()?;
+        left.extend(right.into_iter().rev());
+        Some(std::mem::replace(&mut self.focus, Tree::new(value, left)))
+    }
+}
+
+// Builds the tree of the results of `f` at every subtree of the focus, leaving `x` as it was.
+fn extend_tree<TIn, TOut, F>(f: &F, x: &mut TreeZipper<TIn>) -> Tree<TOut>
+where
+    F: Fn(&TreeZipper<TIn>) -> TOut,
+{
+    let value = f(x);
+    let children = (0..x.focus.children.len())
+        .map(|index| {
+            x.down(index);
+            let child = extend_tree(f, x);
+            x.up();
+            child
+        })
+        .collect();
+    Tree::new(value, children)
+}
+
+// Runs `f` at every subtree of the whole tree, and focuses the result where `x` was focused.
+fn extend_with<TIn, TOut, F>(f: F, mut x: TreeZipper<TIn>) -> TreeZipper<TOut>
+where
+    F: Fn(&TreeZipper<TIn>) -> TOut,
+{
+    let path = x.path();
+    x.root();
+    let mut result = TreeZipper::new(extend_tree(&f, &mut x));
+    for index in path {
+        result.down(index);
+    }
+    result
+}
+
+type_con!(pub struct TypeCon for<T> TreeZipper<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        let map_trees = |trees: &Vec<Tree<TIn>>| trees.iter().map(|t| fmap_tree(&f, t)).collect();
+        TreeZipper {
+            focus: fmap_tree(&f, &x.focus),
+            parents: x
+                .parents
+                .iter()
+                .map(|crumb| Crumb {
+                    value: f(&crumb.value),
+                    left: map_trees(&crumb.left),
+                    right: map_trees(&crumb.right),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        let map_trees =
+            |trees: Vec<Tree<TIn>>| trees.into_iter().map(|t| lmap_tree(&f, t)).collect();
+        TreeZipper {
+            focus: lmap_tree(&f, x.focus),
+            parents: x
+                .parents
+                .into_iter()
+                .map(|crumb| Crumb {
+                    value: f(crumb.value),
+                    left: map_trees(crumb.left),
+                    right: map_trees(crumb.right),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        let mut values = Vec::new();
+        preorder_values(x.into_tree(), &mut values);
+        values
+            .into_iter()
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        let mut values = Vec::new();
+        for crumb in &x.parents {
+            values.push(&crumb.value);
+            for sibling in &crumb.left {
+                values.extend(sibling.preorder());
+            }
+        }
+        values.extend(x.focus.preorder());
+        for crumb in x.parents.iter().rev() {
+            for sibling in crumb.right.iter().rev() {
+                values.extend(sibling.preorder());
+            }
+        }
+        values
+            .into_iter()
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Comonad for TypeCon {
+    fn extract<T>(x: &<TypeCon as WithTypeArg<T>>::Type) -> T
+    where
+        T: Clone,
+    {
+        x.focus.value.clone()
+    }
+
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&<TypeCon as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        extend_with(f, x.clone())
+    }
+}
+
+impl LinearComonad for TypeCon {
+    fn lextract<T>(x: <TypeCon as WithTypeArg<T>>::Type) -> T {
+        x.focus.value
+    }
+
+    fn lduplicate<T>(
+        x: <TypeCon as WithTypeArg<T>>::Type,
+    ) -> <TypeCon as WithTypeArg<<TypeCon as WithTypeArg<T>>::Type>>::Type
+    where
+        T: Clone,
+    {
+        extend_with(|y| y.clone(), x)
+    }
+}
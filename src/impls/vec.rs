@@ -1,16 +1,7 @@
+use crate::traversable::sequence_iter;
 use crate::*;
 
-pub struct TypeCon;
-
-impl<T> TypeAppParam for Vec<T> {
-    type Param = T;
-}
-
-impl<T> TypeApp<TypeCon, T> for Vec<T> {}
-
-impl<T> WithTypeArg<T> for TypeCon {
-    type Type = Vec<T>;
-}
+type_con!(pub struct TypeCon for<T> Vec<T>);
 
 impl Functor for TypeCon {
     fn fmap<TIn, TOut, F>(
@@ -59,6 +50,134 @@ impl Applicative for TypeCon {
             })
             .collect()
     }
+
+    fn seq_left<TIn1, TIn2>(
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TIn1>>::Type
+    where
+        TIn1: Clone,
+    {
+        x1.iter()
+            .flat_map(|y1| std::iter::repeat_n(y1, x2.len()).cloned())
+            .collect()
+    }
+
+    fn seq_right<TIn1, TIn2>(
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TIn2>>::Type
+    where
+        TIn2: Clone,
+    {
+        x1.iter().flat_map(|_| x2.iter().cloned()).collect()
+    }
+
+    fn zip_a<TIn1, TIn2>(
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<(TIn1, TIn2)>>::Type
+    where
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        x1.iter()
+            .flat_map(|y1| x2.iter().map(move |y2| (y1.clone(), y2.clone())))
+            .collect()
+    }
+}
+
+impl ApplicativeN for TypeCon {
+    fn lift3<TIn1, TIn2, TIn3, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3) -> TOut,
+    {
+        let f = &f;
+        x1.iter()
+            .flat_map(|y1| {
+                x2.iter()
+                    .flat_map(move |y2| x3.iter().map(move |y3| f(y1, y2, y3)))
+            })
+            .collect()
+    }
+
+    fn lift4<TIn1, TIn2, TIn3, TIn4, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon as WithTypeArg<TIn4>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4) -> TOut,
+    {
+        let f = &f;
+        x1.iter()
+            .flat_map(|y1| {
+                x2.iter().flat_map(move |y2| {
+                    x3.iter()
+                        .flat_map(move |y3| x4.iter().map(move |y4| f(y1, y2, y3, y4)))
+                })
+            })
+            .collect()
+    }
+
+    fn lift5<TIn1, TIn2, TIn3, TIn4, TIn5, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon as WithTypeArg<TIn4>>::Type,
+        x5: &<TypeCon as WithTypeArg<TIn5>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5) -> TOut,
+    {
+        let f = &f;
+        x1.iter()
+            .flat_map(|y1| {
+                x2.iter().flat_map(move |y2| {
+                    x3.iter().flat_map(move |y3| {
+                        x4.iter()
+                            .flat_map(move |y4| x5.iter().map(move |y5| f(y1, y2, y3, y4, y5)))
+                    })
+                })
+            })
+            .collect()
+    }
+
+    fn lift6<TIn1, TIn2, TIn3, TIn4, TIn5, TIn6, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+        x3: &<TypeCon as WithTypeArg<TIn3>>::Type,
+        x4: &<TypeCon as WithTypeArg<TIn4>>::Type,
+        x5: &<TypeCon as WithTypeArg<TIn5>>::Type,
+        x6: &<TypeCon as WithTypeArg<TIn6>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2, &TIn3, &TIn4, &TIn5, &TIn6) -> TOut,
+    {
+        let f = &f;
+        x1.iter()
+            .flat_map(|y1| {
+                x2.iter().flat_map(move |y2| {
+                    x3.iter().flat_map(move |y3| {
+                        x4.iter().flat_map(move |y4| {
+                            x5.iter().flat_map(move |y5| {
+                                x6.iter().map(move |y6| f(y1, y2, y3, y4, y5, y6))
+                            })
+                        })
+                    })
+                })
+            })
+            .collect()
+    }
 }
 
 impl Monad for TypeCon {
@@ -73,6 +192,62 @@ impl Monad for TypeCon {
     }
 }
 
+impl LinearApplicativeMut for TypeCon {
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <TypeCon as WithTypeArg<TFunc>>::Type,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn) -> TOut,
+        TIn: Clone,
+    {
+        let n = f.len();
+        f.into_iter()
+            .zip(clone_until_last(x, n))
+            .flat_map(|(f_val, x_val)| x_val.into_iter().map(f_val))
+            .collect()
+    }
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        mut f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        let mut result = Vec::with_capacity(x1.len() * x2.len());
+        let n1 = x1.len();
+        for (x1_val, x2_vals) in x1.into_iter().zip(clone_until_last(x2, n1)) {
+            let n2 = x2_vals.len();
+            for (x1_val, x2_val) in clone_until_last(x1_val, n2).zip(x2_vals) {
+                result.push(f(x1_val, x2_val));
+            }
+        }
+        result
+    }
+}
+
+impl LinearMonadMut for TypeCon {
+    fn lbind_mut<TIn, TOut, F>(
+        x: <Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: FnMut(TIn) -> <Self as WithTypeArg<TOut>>::Type,
+    {
+        x.into_iter().flat_map(f).collect()
+    }
+}
+
+// Produces `n` copies of `x`, moving `x` itself into the last one rather than cloning it.
+pub(super) fn clone_until_last<T: Clone>(x: T, n: usize) -> impl Iterator<Item = T> {
+    let mut x = Some(x);
+    (0..n).filter_map(move |i| if i + 1 == n { x.take() } else { x.clone() })
+}
+
 impl LinearFoldable for TypeCon {
     fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
     where
@@ -87,23 +262,17 @@ impl Foldable for TypeCon {
     where
         F: Fn(&TIn, TOut) -> TOut,
     {
-        x.into_iter().fold(init, |acc, next_val| f(next_val, acc))
+        x.iter().fold(init, |acc, next_val| f(next_val, acc))
     }
 }
-/*
+
 impl LinearTraversable for TypeCon {
     fn sequence<TApplicative, T>(
         x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
     ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
     where
-        TApplicative:
-            LinearApplicative + WithTypeArg<T> + WithTypeArg<<Self as WithTypeArg<T>>::Type>,
-        Self: WithTypeArg<T> + WithTypeArg<<TApplicative as WithTypeArg<T>>::Type> {
-            let init = lift::<TApplicative, Vec<T>>(vec!());
-            lfoldr(|x:Vec<T>, ys| llift2(|a, b| pure_append(a,b), x, ys), lift(vec!()), x)
-        }}
-fn pure_append<T>(elem: T, mut vec: Vec<T>) -> Vec<T> {
-    vec.push(elem);
-    vec
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        sequence_iter::<TApplicative, T, _>(x)
+    }
 }
-*/
@@ -0,0 +1,143 @@
+use super::vec::clone_until_last;
+use crate::traversable::sequence_iter;
+use crate::*;
+use std::collections::VecDeque;
+
+type_con!(pub struct TypeCon for<T> VecDeque<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        x.iter().map(f).collect()
+    }
+}
+
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        x.into_iter().map(f).collect()
+    }
+}
+
+impl Lift for TypeCon {
+    fn lift<T>(x: T) -> <TypeCon as WithTypeArg<T>>::Type {
+        std::iter::once(x).collect()
+    }
+}
+
+impl Applicative for TypeCon {
+    fn lift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        let f = &f;
+        x1.iter()
+            .flat_map(|x1_val| x2.iter().map(move |x2_val| f(x1_val, x2_val)))
+            .collect()
+    }
+}
+
+impl Monad for TypeCon {
+    fn bind<TIn, TOut, F>(
+        x: &<Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> <Self as WithTypeArg<TOut>>::Type,
+    {
+        x.iter().flat_map(f).collect()
+    }
+}
+
+impl LinearApplicativeMut for TypeCon {
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <TypeCon as WithTypeArg<TFunc>>::Type,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn) -> TOut,
+        TIn: Clone,
+    {
+        let n = f.len();
+        f.into_iter()
+            .zip(clone_until_last(x, n))
+            .flat_map(|(f_val, x_val)| x_val.into_iter().map(f_val))
+            .collect()
+    }
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        mut f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        let mut result = VecDeque::with_capacity(x1.len() * x2.len());
+        let n1 = x1.len();
+        for (x1_val, x2_vals) in x1.into_iter().zip(clone_until_last(x2, n1)) {
+            let n2 = x2_vals.len();
+            for (x1_val, x2_val) in clone_until_last(x1_val, n2).zip(x2_vals) {
+                result.push_back(f(x1_val, x2_val));
+            }
+        }
+        result
+    }
+}
+
+impl LinearMonadMut for TypeCon {
+    fn lbind_mut<TIn, TOut, F>(
+        x: <Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        F: FnMut(TIn) -> <Self as WithTypeArg<TOut>>::Type,
+    {
+        x.into_iter().flat_map(f).collect()
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_iter().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.iter().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        sequence_iter::<TApplicative, T, _>(x)
+    }
+}
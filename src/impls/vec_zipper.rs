@@ -0,0 +1,259 @@
+use crate::traversable::sequence_iter;
+use crate::*;
+
+// A non empty list with a focus, which can be moved left and right and edited in constant time.
+//
+// `left` holds the elements before the focus in order, and `right` holds the elements after it in
+// reverse, so the neighbours of the focus are at the ends of both.
+//
+// As a Comonad, the context of each element is the zipper focused on it, so `extend` can look at
+// the neighbours of every element.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VecZipper<T> {
+    left: Vec<T>,
+    focus: T,
+    right: Vec<T>,
+}
+
+impl<T> VecZipper<T> {
+    pub fn new(left: Vec<T>, focus: T, mut right: Vec<T>) -> Self {
+        right.reverse();
+        VecZipper { left, focus, right }
+    }
+
+    // Focuses on the first element. Returns None if the Vec is empty.
+    pub fn from_vec(x: Vec<T>) -> Option<Self> {
+        let mut right = x;
+        right.reverse();
+        let focus = right.pop()?;
+        Some(VecZipper {
+            left: Vec::new(),
+            focus,
+            right,
+        })
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        let mut result = self.left;
+        result.reserve(self.right.len() + 1);
+        result.push(self.focus);
+        result.extend(self.right.into_iter().rev());
+        result
+    }
+
+    pub fn focus(&self) -> &T {
+        &self.focus
+    }
+
+    // The index of the focus
+    pub fn position(&self) -> usize {
+        self.left.len()
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.left.len() + self.right.len() + 1
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.left
+            .iter()
+            .chain(std::iter::once(&self.focus))
+            .chain(self.right.iter().rev())
+    }
+
+    pub fn peek_left(&self) -> Option<&T> {
+        self.left.last()
+    }
+
+    pub fn peek_right(&self) -> Option<&T> {
+        self.right.last()
+    }
+
+    // Moves the focus one place left. Returns false, and leaves the zipper alone, at the start.
+    pub fn left(&mut self) -> bool {
+        match self.left.pop() {
+            Some(x) => {
+                self.right.push(std::mem::replace(&mut self.focus, x));
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Moves the focus one place right. Returns false, and leaves the zipper alone, at the end.
+    pub fn right(&mut self) -> bool {
+        match self.right.pop() {
+            Some(x) => {
+                self.left.push(std::mem::replace(&mut self.focus, x));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn start(&mut self) {
+        while self.left() {}
+    }
+
+    // Replaces the focus, returning the old one.
+    pub fn set(&mut self, x: T) -> T {
+        std::mem::replace(&mut self.focus, x)
+    }
+
+    pub fn modify<F: FnOnce(&mut T)>(&mut self, f: F) {
+        f(&mut self.focus)
+    }
+
+    pub fn insert_left(&mut self, x: T) {
+        self.left.push(x);
+    }
+
+    pub fn insert_right(&mut self, x: T) {
+        self.right.push(x);
+    }
+
+    // Removes the focus, moving the focus to the element on its right, or if there isn't one, its
+    // left. Returns None, and leaves the zipper alone, if the focus is the only element.
+    pub fn remove(&mut self) -> Option<T> {
+        let next = self.right.pop().or_else(|| self.left.pop())?;
+        Some(std::mem::replace(&mut self.focus, next))
+    }
+}
+
+// Builds a zipper of the results of `f` at every position of `x`, focused where `x` is.
+fn extend_with<TIn, TOut, F>(f: F, mut x: VecZipper<TIn>) -> VecZipper<TOut>
+where
+    F: Fn(&VecZipper<TIn>) -> TOut,
+{
+    let position = x.position();
+    x.start();
+    let mut results = Vec::with_capacity(x.len());
+    results.push(f(&x));
+    while x.right() {
+        results.push(f(&x));
+    }
+    let mut right = results.split_off(position);
+    right.reverse();
+    let focus = right.pop().unwrap();
+    VecZipper {
+        left: results,
+        focus,
+        right,
+    }
+}
+
+type_con!(pub struct TypeCon for<T> VecZipper<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        let left = x.left.iter().map(&f).collect();
+        let focus = f(&x.focus);
+        let mut right: Vec<TOut> = x.right.iter().rev().map(&f).collect();
+        right.reverse();
+        VecZipper { left, focus, right }
+    }
+}
+
+// Visits the elements in order, so the default traversal runs effects left to right.
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        let left = x.left.into_iter().map(&f).collect();
+        let focus = f(x.focus);
+        let mut right: Vec<TOut> = x.right.into_iter().rev().map(&f).collect();
+        right.reverse();
+        VecZipper { left, focus, right }
+    }
+}
+
+impl LinearFoldable for TypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.into_vec()
+            .into_iter()
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Foldable for TypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.iter().rev().fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl LinearTraversable for TypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        // `right` is stored in reverse, so its effects are combined from its end.
+        let left_and_focus = <TApplicative as LinearApplicative>::llift2(
+            |left: Vec<T>, focus: T| (left, focus),
+            sequence_iter::<TApplicative, T, _>(x.left),
+            x.focus,
+        );
+        <TApplicative as LinearApplicative>::llift2(
+            |(left, focus), mut right: Vec<T>| {
+                right.reverse();
+                VecZipper { left, focus, right }
+            },
+            left_and_focus,
+            sequence_iter::<TApplicative, T, _>(x.right.into_iter().rev()),
+        )
+    }
+}
+
+impl Comonad for TypeCon {
+    fn extract<T>(x: &<TypeCon as WithTypeArg<T>>::Type) -> T
+    where
+        T: Clone,
+    {
+        x.focus.clone()
+    }
+
+    fn extend<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&<TypeCon as WithTypeArg<TIn>>::Type) -> TOut,
+        TIn: Clone,
+    {
+        extend_with(f, x.clone())
+    }
+}
+
+impl LinearComonad for TypeCon {
+    fn lextract<T>(x: <TypeCon as WithTypeArg<T>>::Type) -> T {
+        x.focus
+    }
+
+    fn lduplicate<T>(
+        x: <TypeCon as WithTypeArg<T>>::Type,
+    ) -> <TypeCon as WithTypeArg<<TypeCon as WithTypeArg<T>>::Type>>::Type
+    where
+        T: Clone,
+    {
+        extend_with(|y| y.clone(), x)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::traversable::sequence_iter;
+use crate::*;
+
+// A Vec whose Applicative instance zips values position by position, rather than taking every
+// combination like Vec does. This is Haskell's ZipList.
+//
+// `lift` has to produce a value which zips with a list of any length, which is an infinite list
+// of the same value. That's what `Repeat` is. Zipping with it behaves like zipping with a list
+// as long as the other one.
+//
+// A `Repeat` stands for infinitely many values, so it can't be folded or traversed. Those are on
+// `FiniteZipVec` instead, which `finite` gives for any `ZipVec` that isn't a `Repeat`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ZipVec<T> {
+    Finite(Vec<T>),
+    Repeat(T),
+}
+
+impl<T> From<Vec<T>> for ZipVec<T> {
+    fn from(x: Vec<T>) -> Self {
+        ZipVec::Finite(x)
+    }
+}
+
+impl<T> ZipVec<T> {
+    // Returns None for a `Repeat`
+    pub fn finite(self) -> Option<FiniteZipVec<T>> {
+        match self {
+            ZipVec::Finite(xs) => Some(FiniteZipVec(xs)),
+            ZipVec::Repeat(_) => None,
+        }
+    }
+}
+
+// The values of a `ZipVec` which isn't a `Repeat`, which can be folded and traversed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FiniteZipVec<T>(pub Vec<T>);
+
+impl<T> From<FiniteZipVec<T>> for ZipVec<T> {
+    fn from(x: FiniteZipVec<T>) -> Self {
+        ZipVec::Finite(x.0)
+    }
+}
+
+type_con!(pub struct TypeCon for<T> ZipVec<T>);
+
+impl Functor for TypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        match x {
+            ZipVec::Finite(xs) => ZipVec::Finite(xs.iter().map(f).collect()),
+            ZipVec::Repeat(y) => ZipVec::Repeat(f(y)),
+        }
+    }
+}
+
+impl LinearFunctor for TypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        match x {
+            ZipVec::Finite(xs) => ZipVec::Finite(xs.into_iter().map(f).collect()),
+            ZipVec::Repeat(y) => ZipVec::Repeat(f(y)),
+        }
+    }
+}
+
+impl Lift for TypeCon {
+    fn lift<T>(x: T) -> <TypeCon as WithTypeArg<T>>::Type {
+        ZipVec::Repeat(x)
+    }
+}
+
+impl Applicative for TypeCon {
+    fn lift2<TIn1, TIn2, TOut, TFunc>(
+        f: TFunc,
+        x1: &<TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: &<TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: Fn(&TIn1, &TIn2) -> TOut,
+    {
+        match (x1, x2) {
+            (ZipVec::Finite(ys1), ZipVec::Finite(ys2)) => {
+                ZipVec::Finite(ys1.iter().zip(ys2).map(|(y1, y2)| f(y1, y2)).collect())
+            }
+            (ZipVec::Finite(ys1), ZipVec::Repeat(y2)) => {
+                ZipVec::Finite(ys1.iter().map(|y1| f(y1, y2)).collect())
+            }
+            (ZipVec::Repeat(y1), ZipVec::Finite(ys2)) => {
+                ZipVec::Finite(ys2.iter().map(|y2| f(y1, y2)).collect())
+            }
+            (ZipVec::Repeat(y1), ZipVec::Repeat(y2)) => ZipVec::Repeat(f(y1, y2)),
+        }
+    }
+}
+
+impl LinearApplicativeMut for TypeCon {
+    fn lap_mut<TIn, TOut, TFunc>(
+        f: <TypeCon as WithTypeArg<TFunc>>::Type,
+        x: <TypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn) -> TOut,
+        TIn: Clone,
+    {
+        match (f, x) {
+            (ZipVec::Finite(fs), ZipVec::Finite(ys)) => {
+                ZipVec::Finite(fs.into_iter().zip(ys).map(|(mut f, y)| f(y)).collect())
+            }
+            (ZipVec::Finite(fs), ZipVec::Repeat(y)) => {
+                ZipVec::Finite(fs.into_iter().map(|mut f| f(y.clone())).collect())
+            }
+            (ZipVec::Repeat(f), ZipVec::Finite(ys)) => {
+                ZipVec::Finite(ys.into_iter().map(f).collect())
+            }
+            (ZipVec::Repeat(mut f), ZipVec::Repeat(y)) => ZipVec::Repeat(f(y)),
+        }
+    }
+
+    fn llift2_mut<TIn1, TIn2, TOut, TFunc>(
+        mut f: TFunc,
+        x1: <TypeCon as WithTypeArg<TIn1>>::Type,
+        x2: <TypeCon as WithTypeArg<TIn2>>::Type,
+    ) -> <TypeCon as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnMut(TIn1, TIn2) -> TOut,
+        TIn1: Clone,
+        TIn2: Clone,
+    {
+        match (x1, x2) {
+            (ZipVec::Finite(ys1), ZipVec::Finite(ys2)) => {
+                ZipVec::Finite(ys1.into_iter().zip(ys2).map(|(y1, y2)| f(y1, y2)).collect())
+            }
+            (ZipVec::Finite(ys1), ZipVec::Repeat(y2)) => {
+                ZipVec::Finite(ys1.into_iter().map(|y1| f(y1, y2.clone())).collect())
+            }
+            (ZipVec::Repeat(y1), ZipVec::Finite(ys2)) => {
+                ZipVec::Finite(ys2.into_iter().map(|y2| f(y1.clone(), y2)).collect())
+            }
+            (ZipVec::Repeat(y1), ZipVec::Repeat(y2)) => ZipVec::Repeat(f(y1, y2)),
+        }
+    }
+}
+
+type_con!(pub struct FiniteTypeCon for<T> FiniteZipVec<T>);
+
+impl Functor for FiniteTypeCon {
+    fn fmap<TIn, TOut, F>(
+        f: F,
+        x: &<FiniteTypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <FiniteTypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(&TIn) -> TOut,
+    {
+        FiniteZipVec(x.0.iter().map(f).collect())
+    }
+}
+
+impl LinearFunctor for FiniteTypeCon {
+    fn lmap<TIn, TOut, F>(
+        f: F,
+        x: <FiniteTypeCon as WithTypeArg<TIn>>::Type,
+    ) -> <FiniteTypeCon as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        FiniteZipVec(x.0.into_iter().map(f).collect())
+    }
+}
+
+impl LinearFoldable for FiniteTypeCon {
+    fn lfoldr<F, TIn, TOut>(f: F, init: TOut, x: <Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(TIn, TOut) -> TOut,
+    {
+        x.0.into_iter()
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl Foldable for FiniteTypeCon {
+    fn foldr<F, TIn, TOut>(f: F, init: TOut, x: &<Self as WithTypeArg<TIn>>::Type) -> TOut
+    where
+        F: Fn(&TIn, TOut) -> TOut,
+    {
+        x.0.iter()
+            .rev()
+            .fold(init, |acc, next_val| f(next_val, acc))
+    }
+}
+
+impl LinearTraversable for FiniteTypeCon {
+    fn sequence<TApplicative, T>(
+        x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
+    ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
+    where
+        TApplicative: LinearApplicative + gat::HigherKinded,
+    {
+        <TApplicative as LinearApplicative>::lap(
+            <TApplicative as Lift>::lift(FiniteZipVec),
+            sequence_iter::<TApplicative, T, _>(x.0),
+        )
+    }
+}
@@ -0,0 +1,721 @@
+use crate::*;
+use std::marker::PhantomData;
+
+// Van Laarhoven optics, for reading and updating parts of immutable structures.
+//
+// In Haskell a lens from `s` to `a` is a function `forall f. Functor f => (a -> f b) -> s -> f t`,
+// which lifts an effectful update of the part to one of the whole. Rust closures can't be
+// polymorphic like that, so here each kind of optic is a trait with the polymorphic function as a
+// generic method, e.g. `Lens::lens_of`, and the functor is picked at the call site:
+//
+// * `over` and `set` run the optic with the `Identity` functor, so the update is all you get back,
+// * `to_list_of` and `preview` run it with a constant functor, which ignores the update and just
+//   collects the parts it's given, and
+// * `traverse_of` runs it with any `LinearApplicative`, such as `Option` or `Result`, so an update
+//   can fail.
+//
+// The functors are the linear (by value) ones, so nothing needs to be cloned while updating.
+//
+// Like in Haskell, a `Traversal` focuses on any number of parts, a `Lens` on exactly one, a
+// `Prism` on one alternative which may or may not be there, an `Iso` on a different
+// representation of the whole, and a `Getter` can only read. Optics compose with `then`, and the
+// result is the most general kind both sides are, so a lens then a traversal is a traversal.
+//
+// These are in their own module rather than re-exported from the crate root, as names like `set`
+// and `to` are too short to take over everywhere. Use `haskell_bits::lens::*`.
+//
+// The four types are the ones in Haskell's `Lens s t a b`: the optic turns an `S` into a `T` by
+// turning its `A`s into `B`s. They're usually `S = T` and `A = B`.
+pub trait Optic {
+    type S;
+    type T;
+    type A;
+    type B;
+
+    // Composes this optic with one which looks inside the parts this one focuses on.
+    fn then<O>(self, inner: O) -> Composed<Self, O>
+    where
+        Self: Sized,
+        O: Optic<S = Self::A, T = Self::B>,
+    {
+        Composed { outer: self, inner }
+    }
+}
+
+pub trait Traversal: Optic {
+    fn traverse_of<TApplicative, F>(
+        &self,
+        f: F,
+        s: Self::S,
+    ) -> <TApplicative as WithTypeArg<Self::T>>::Type
+    where
+        TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+        F: Fn(Self::A) -> <TApplicative as WithTypeArg<Self::B>>::Type;
+}
+
+// A Getter can only be used to view its part, but the view doesn't have to be stored anywhere.
+// Its `T` and `B` are the same as its `S` and `A`.
+pub trait Getter: Optic {
+    fn view_of(&self, s: &Self::S) -> Self::A;
+}
+
+pub trait Lens: Traversal + Getter {
+    fn lens_of<TFunctor, F>(&self, f: F, s: Self::S) -> <TFunctor as WithTypeArg<Self::T>>::Type
+    where
+        TFunctor: gat::HigherKinded + LinearFunctor,
+        F: Fn(Self::A) -> <TFunctor as WithTypeArg<Self::B>>::Type;
+}
+
+// A Prism focuses on one alternative of a sum type. `matching` gives back the whole, already
+// converted to a `T`, if it's a different alternative, and `review` builds the alternative.
+pub trait Prism: Traversal {
+    fn matching(&self, s: Self::S) -> Result<Self::A, Self::T>;
+
+    fn review(&self, b: Self::B) -> Self::T;
+}
+
+// An Iso is both a Lens and a Prism, as the part is the whole in a different form.
+pub trait Iso: Lens + Prism {}
+
+// Holds the types of the optics made from functions, which otherwise only appear in the bounds.
+type Types<S, T, A, B> = PhantomData<fn(S, B) -> (T, A)>;
+
+// A lens made from a getter and a setter. The setter builds the new whole from the old one and
+// the new part.
+//
+// Like the other optics here, it's `Clone` when its functions are, so it can be reused after
+// being composed with `then`.
+pub struct FnLens<S, T, A, B, G, U> {
+    get: G,
+    set: U,
+    _types: Types<S, T, A, B>,
+}
+
+impl<S, T, A, B, G: Clone, U: Clone> Clone for FnLens<S, T, A, B, G, U> {
+    fn clone(&self) -> Self {
+        FnLens {
+            get: self.get.clone(),
+            set: self.set.clone(),
+            _types: PhantomData,
+        }
+    }
+}
+
+// lens(get, set)
+pub fn lens<S, T, A, B, G, U>(get: G, set: U) -> FnLens<S, T, A, B, G, U>
+where
+    G: Fn(&S) -> A,
+    U: Fn(&S, B) -> T,
+{
+    FnLens {
+        get,
+        set,
+        _types: PhantomData,
+    }
+}
+
+impl<S, T, A, B, G, U> Optic for FnLens<S, T, A, B, G, U> {
+    type S = S;
+    type T = T;
+    type A = A;
+    type B = B;
+}
+
+impl<S, T, A, B, G, U> Traversal for FnLens<S, T, A, B, G, U>
+where
+    G: Fn(&S) -> A,
+    U: Fn(&S, B) -> T,
+{
+    fn traverse_of<TApplicative, F>(&self, f: F, s: S) -> <TApplicative as WithTypeArg<T>>::Type
+    where
+        TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+        F: Fn(A) -> <TApplicative as WithTypeArg<B>>::Type,
+    {
+        self.lens_of::<TApplicative, F>(f, s)
+    }
+}
+
+impl<S, T, A, B, G, U> Getter for FnLens<S, T, A, B, G, U>
+where
+    G: Fn(&S) -> A,
+{
+    fn view_of(&self, s: &S) -> A {
+        (self.get)(s)
+    }
+}
+
+impl<S, T, A, B, G, U> Lens for FnLens<S, T, A, B, G, U>
+where
+    G: Fn(&S) -> A,
+    U: Fn(&S, B) -> T,
+{
+    fn lens_of<TFunctor, F>(&self, f: F, s: S) -> <TFunctor as WithTypeArg<T>>::Type
+    where
+        TFunctor: gat::HigherKinded + LinearFunctor,
+        F: Fn(A) -> <TFunctor as WithTypeArg<B>>::Type,
+    {
+        let part = (self.get)(&s);
+        <TFunctor as LinearFunctor>::lmap(|b| (self.set)(&s, b), f(part))
+    }
+}
+
+// A lens made from a getter, a function splitting the whole into the part and the rest, and one
+// joining the rest with a new part. Updates move the whole rather than rebuilding it from a
+// borrow, so nothing is copied but what `get` copies for `view`.
+pub struct SplitLens<S, T, A, B, R, G, P, J> {
+    get: G,
+    split: P,
+    join: J,
+    _types: Types<S, T, A, B>,
+    _rest: PhantomData<fn() -> R>,
+}
+
+impl<S, T, A, B, R, G: Clone, P: Clone, J: Clone> Clone for SplitLens<S, T, A, B, R, G, P, J> {
+    fn clone(&self) -> Self {
+        SplitLens {
+            get: self.get.clone(),
+            split: self.split.clone(),
+            join: self.join.clone(),
+            _types: PhantomData,
+            _rest: PhantomData,
+        }
+    }
+}
+
+// split_lens(get, split, join)
+pub fn split_lens<S, T, A, B, R, G, P, J>(
+    get: G,
+    split: P,
+    join: J,
+) -> SplitLens<S, T, A, B, R, G, P, J>
+where
+    G: Fn(&S) -> A,
+    P: Fn(S) -> (A, R),
+    J: Fn(R, B) -> T,
+{
+    SplitLens {
+        get,
+        split,
+        join,
+        _types: PhantomData,
+        _rest: PhantomData,
+    }
+}
+
+impl<S, T, A, B, R, G, P, J> Optic for SplitLens<S, T, A, B, R, G, P, J> {
+    type S = S;
+    type T = T;
+    type A = A;
+    type B = B;
+}
+
+// A `LinearApplicative` uses its function at most once, so the rest can be moved into it.
+impl<S, T, A, B, R, G, P, J> Traversal for SplitLens<S, T, A, B, R, G, P, J>
+where
+    P: Fn(S) -> (A, R),
+    J: Fn(R, B) -> T,
+{
+    fn traverse_of<TApplicative, F>(&self, f: F, s: S) -> <TApplicative as WithTypeArg<T>>::Type
+    where
+        TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+        F: Fn(A) -> <TApplicative as WithTypeArg<B>>::Type,
+    {
+        let (part, rest) = (self.split)(s);
+        <TApplicative as LinearApplicative>::lap(
+            <TApplicative as Lift>::lift(|b| (self.join)(rest, b)),
+            f(part),
+        )
+    }
+}
+
+impl<S, T, A, B, R, G, P, J> Getter for SplitLens<S, T, A, B, R, G, P, J>
+where
+    G: Fn(&S) -> A,
+{
+    fn view_of(&self, s: &S) -> A {
+        (self.get)(s)
+    }
+}
+
+// Any functor can be used here, and one may use the setter more than once, so each use gets its
+// own copy of the rest.
+impl<S, T, A, B, R, G, P, J> Lens for SplitLens<S, T, A, B, R, G, P, J>
+where
+    R: Clone,
+    G: Fn(&S) -> A,
+    P: Fn(S) -> (A, R),
+    J: Fn(R, B) -> T,
+{
+    fn lens_of<TFunctor, F>(&self, f: F, s: S) -> <TFunctor as WithTypeArg<T>>::Type
+    where
+        TFunctor: gat::HigherKinded + LinearFunctor,
+        F: Fn(A) -> <TFunctor as WithTypeArg<B>>::Type,
+    {
+        let (part, rest) = (self.split)(s);
+        <TFunctor as LinearFunctor>::lmap(|b| (self.join)(rest.clone(), b), f(part))
+    }
+}
+
+pub struct FnPrism<S, T, A, B, M, R> {
+    matching: M,
+    review: R,
+    _types: Types<S, T, A, B>,
+}
+
+impl<S, T, A, B, M: Clone, R: Clone> Clone for FnPrism<S, T, A, B, M, R> {
+    fn clone(&self) -> Self {
+        FnPrism {
+            matching: self.matching.clone(),
+            review: self.review.clone(),
+            _types: PhantomData,
+        }
+    }
+}
+
+// prism(matching, review)
+pub fn prism<S, T, A, B, M, R>(matching: M, review: R) -> FnPrism<S, T, A, B, M, R>
+where
+    M: Fn(S) -> Result<A, T>,
+    R: Fn(B) -> T,
+{
+    FnPrism {
+        matching,
+        review,
+        _types: PhantomData,
+    }
+}
+
+impl<S, T, A, B, M, R> Optic for FnPrism<S, T, A, B, M, R> {
+    type S = S;
+    type T = T;
+    type A = A;
+    type B = B;
+}
+
+impl<S, T, A, B, M, R> Traversal for FnPrism<S, T, A, B, M, R>
+where
+    M: Fn(S) -> Result<A, T>,
+    R: Fn(B) -> T,
+{
+    fn traverse_of<TApplicative, F>(&self, f: F, s: S) -> <TApplicative as WithTypeArg<T>>::Type
+    where
+        TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+        F: Fn(A) -> <TApplicative as WithTypeArg<B>>::Type,
+    {
+        match (self.matching)(s) {
+            Ok(a) => <TApplicative as LinearFunctor>::lmap(&self.review, f(a)),
+            Err(t) => <TApplicative as Lift>::lift(t),
+        }
+    }
+}
+
+impl<S, T, A, B, M, R> Prism for FnPrism<S, T, A, B, M, R>
+where
+    M: Fn(S) -> Result<A, T>,
+    R: Fn(B) -> T,
+{
+    fn matching(&self, s: S) -> Result<A, T> {
+        (self.matching)(s)
+    }
+
+    fn review(&self, b: B) -> T {
+        (self.review)(b)
+    }
+}
+
+pub struct FnIso<S, T, A, B, F, G> {
+    to: F,
+    from: G,
+    _types: Types<S, T, A, B>,
+}
+
+impl<S, T, A, B, F: Clone, G: Clone> Clone for FnIso<S, T, A, B, F, G> {
+    fn clone(&self) -> Self {
+        FnIso {
+            to: self.to.clone(),
+            from: self.from.clone(),
+            _types: PhantomData,
+        }
+    }
+}
+
+// iso(to, from)
+pub fn iso<S, T, A, B, F, G>(to: F, from: G) -> FnIso<S, T, A, B, F, G>
+where
+    F: Fn(&S) -> A,
+    G: Fn(B) -> T,
+{
+    FnIso {
+        to,
+        from,
+        _types: PhantomData,
+    }
+}
+
+impl<S, T, A, B, F, G> Optic for FnIso<S, T, A, B, F, G> {
+    type S = S;
+    type T = T;
+    type A = A;
+    type B = B;
+}
+
+impl<S, T, A, B, F, G> Traversal for FnIso<S, T, A, B, F, G>
+where
+    F: Fn(&S) -> A,
+    G: Fn(B) -> T,
+{
+    fn traverse_of<TApplicative, H>(&self, f: H, s: S) -> <TApplicative as WithTypeArg<T>>::Type
+    where
+        TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+        H: Fn(A) -> <TApplicative as WithTypeArg<B>>::Type,
+    {
+        self.lens_of::<TApplicative, H>(f, s)
+    }
+}
+
+impl<S, T, A, B, F, G> Getter for FnIso<S, T, A, B, F, G>
+where
+    F: Fn(&S) -> A,
+{
+    fn view_of(&self, s: &S) -> A {
+        (self.to)(s)
+    }
+}
+
+impl<S, T, A, B, F, G> Lens for FnIso<S, T, A, B, F, G>
+where
+    F: Fn(&S) -> A,
+    G: Fn(B) -> T,
+{
+    fn lens_of<TFunctor, H>(&self, f: H, s: S) -> <TFunctor as WithTypeArg<T>>::Type
+    where
+        TFunctor: gat::HigherKinded + LinearFunctor,
+        H: Fn(A) -> <TFunctor as WithTypeArg<B>>::Type,
+    {
+        <TFunctor as LinearFunctor>::lmap(&self.from, f((self.to)(&s)))
+    }
+}
+
+impl<S, T, A, B, F, G> Prism for FnIso<S, T, A, B, F, G>
+where
+    F: Fn(&S) -> A,
+    G: Fn(B) -> T,
+{
+    fn matching(&self, s: S) -> Result<A, T> {
+        Ok((self.to)(&s))
+    }
+
+    fn review(&self, b: B) -> T {
+        (self.from)(b)
+    }
+}
+
+impl<S, T, A, B, F, G> Iso for FnIso<S, T, A, B, F, G>
+where
+    F: Fn(&S) -> A,
+    G: Fn(B) -> T,
+{
+}
+
+pub struct FnGetter<S, A, F> {
+    get: F,
+    _types: PhantomData<fn(S) -> A>,
+}
+
+impl<S, A, F: Clone> Clone for FnGetter<S, A, F> {
+    fn clone(&self) -> Self {
+        FnGetter {
+            get: self.get.clone(),
+            _types: PhantomData,
+        }
+    }
+}
+
+// to(get)
+pub fn to<S, A, F>(get: F) -> FnGetter<S, A, F>
+where
+    F: Fn(&S) -> A,
+{
+    FnGetter {
+        get,
+        _types: PhantomData,
+    }
+}
+
+impl<S, A, F> Optic for FnGetter<S, A, F> {
+    type S = S;
+    type T = S;
+    type A = A;
+    type B = A;
+}
+
+impl<S, A, F> Getter for FnGetter<S, A, F>
+where
+    F: Fn(&S) -> A,
+{
+    fn view_of(&self, s: &S) -> A {
+        (self.get)(s)
+    }
+}
+
+// Focuses on every element of a `LinearTraversable`, in the order it traverses them.
+pub struct Traversed<TCon, A, B> {
+    _con: PhantomData<TCon>,
+    _types: PhantomData<fn(A) -> B>,
+}
+
+impl<TCon, A, B> Clone for Traversed<TCon, A, B> {
+    fn clone(&self) -> Self {
+        Traversed {
+            _con: PhantomData,
+            _types: PhantomData,
+        }
+    }
+}
+
+// traversed::<TCon, _, _>()
+pub fn traversed<TCon, A, B>() -> Traversed<TCon, A, B>
+where
+    TCon: gat::HigherKinded + LinearTraversable,
+{
+    Traversed {
+        _con: PhantomData,
+        _types: PhantomData,
+    }
+}
+
+impl<TCon, A, B> Optic for Traversed<TCon, A, B>
+where
+    TCon: gat::HigherKinded,
+{
+    type S = <TCon as WithTypeArg<A>>::Type;
+    type T = <TCon as WithTypeArg<B>>::Type;
+    type A = A;
+    type B = B;
+}
+
+impl<TCon, A, B> Traversal for Traversed<TCon, A, B>
+where
+    TCon: gat::HigherKinded + LinearTraversable,
+{
+    fn traverse_of<TApplicative, F>(
+        &self,
+        f: F,
+        s: <TCon as WithTypeArg<A>>::Type,
+    ) -> <TApplicative as WithTypeArg<<TCon as WithTypeArg<B>>::Type>>::Type
+    where
+        TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+        F: Fn(A) -> <TApplicative as WithTypeArg<B>>::Type,
+    {
+        <TCon as LinearTraversable>::traverse::<TApplicative, A, B, F>(f, s)
+    }
+}
+
+// The result of `then`, which focuses on the parts `inner` focuses on inside each part `outer`
+// focuses on.
+#[derive(Clone)]
+pub struct Composed<O1, O2> {
+    outer: O1,
+    inner: O2,
+}
+
+impl<O1, O2> Optic for Composed<O1, O2>
+where
+    O1: Optic,
+    O2: Optic<S = O1::A, T = O1::B>,
+{
+    type S = O1::S;
+    type T = O1::T;
+    type A = O2::A;
+    type B = O2::B;
+}
+
+impl<O1, O2> Traversal for Composed<O1, O2>
+where
+    O1: Traversal,
+    O2: Traversal<S = O1::A, T = O1::B>,
+{
+    fn traverse_of<TApplicative, F>(
+        &self,
+        f: F,
+        s: O1::S,
+    ) -> <TApplicative as WithTypeArg<O1::T>>::Type
+    where
+        TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+        F: Fn(O2::A) -> <TApplicative as WithTypeArg<O2::B>>::Type,
+    {
+        self.outer
+            .traverse_of::<TApplicative, _>(|a| self.inner.traverse_of::<TApplicative, _>(&f, a), s)
+    }
+}
+
+impl<O1, O2> Getter for Composed<O1, O2>
+where
+    O1: Getter,
+    O2: Getter<S = O1::A, T = O1::B>,
+{
+    fn view_of(&self, s: &O1::S) -> O2::A {
+        self.inner.view_of(&self.outer.view_of(s))
+    }
+}
+
+impl<O1, O2> Lens for Composed<O1, O2>
+where
+    O1: Lens,
+    O2: Lens<S = O1::A, T = O1::B>,
+{
+    fn lens_of<TFunctor, F>(&self, f: F, s: O1::S) -> <TFunctor as WithTypeArg<O1::T>>::Type
+    where
+        TFunctor: gat::HigherKinded + LinearFunctor,
+        F: Fn(O2::A) -> <TFunctor as WithTypeArg<O2::B>>::Type,
+    {
+        self.outer
+            .lens_of::<TFunctor, _>(|a| self.inner.lens_of::<TFunctor, _>(&f, a), s)
+    }
+}
+
+impl<O1, O2> Prism for Composed<O1, O2>
+where
+    O1: Prism,
+    O2: Prism<S = O1::A, T = O1::B>,
+{
+    fn matching(&self, s: O1::S) -> Result<O2::A, O1::T> {
+        match self.outer.matching(s) {
+            Ok(a) => self.inner.matching(a).map_err(|b| self.outer.review(b)),
+            Err(t) => Err(t),
+        }
+    }
+
+    fn review(&self, b: O2::B) -> O1::T {
+        self.outer.review(self.inner.review(b))
+    }
+}
+
+impl<O1, O2> Iso for Composed<O1, O2>
+where
+    O1: Iso,
+    O2: Iso<S = O1::A, T = O1::B>,
+{
+}
+
+// view(&o, &s)
+pub fn view<O>(o: &O, s: &O::S) -> O::A
+where
+    O: Getter,
+{
+    o.view_of(s)
+}
+
+// over(&o, f, s)
+pub fn over<O, F>(o: &O, f: F, s: O::S) -> O::T
+where
+    O: Traversal,
+    F: Fn(O::A) -> O::B,
+{
+    o.traverse_of::<IdentityTypeCon, _>(|a| Identity(f(a)), s).0
+}
+
+// set(&o, b, s)
+pub fn set<O>(o: &O, b: O::B, s: O::S) -> O::T
+where
+    O: Traversal,
+    O::B: Clone,
+{
+    over(o, |_| b.clone(), s)
+}
+
+// review(&o, b)
+pub fn review<O>(o: &O, b: O::B) -> O::T
+where
+    O: Prism,
+{
+    o.review(b)
+}
+
+// to_list_of(&o, s)
+pub fn to_list_of<O>(o: &O, s: O::S) -> Vec<O::A>
+where
+    O: Traversal,
+{
+    o.traverse_of::<ConstTypeCon<Vec<O::A>>, _>(|a| Const(vec![a], PhantomData), s)
+        .0
+}
+
+// preview(&o, s)
+pub fn preview<O>(o: &O, s: O::S) -> Option<O::A>
+where
+    O: Traversal,
+{
+    o.traverse_of::<ConstTypeCon<First<O::A>>, _>(|a| Const(First(Some(a)), PhantomData), s)
+        .0
+         .0
+}
+
+// traverse_of(&o, f, s)
+pub fn traverse_of<TApplicative, O, F, TResult>(
+    o: &O,
+    f: F,
+    s: O::S,
+) -> <TApplicative as WithTypeArg<O::T>>::Type
+where
+    TApplicative: gat::HigherKinded + LinearFunctor + LinearApplicative,
+    O: Traversal,
+    F: Fn(O::A) -> TResult,
+    TResult: TypeApp<TApplicative, O::B>,
+{
+    o.traverse_of::<TApplicative, _>(|a| f(a).into_val(), s)
+}
+
+// The constant functor, which holds an `M` and ignores the functions mapped over it. It's an
+// applicative when `M` is a monoid, combining the `M`s.
+struct Const<M, A>(M, PhantomData<A>);
+
+type_con!(struct ConstTypeCon<M> for<A> Const<M, A>);
+
+impl<M> LinearFunctor for ConstTypeCon<M> {
+    fn lmap<TIn, TOut, F>(
+        _f: F,
+        x: <ConstTypeCon<M> as WithTypeArg<TIn>>::Type,
+    ) -> <ConstTypeCon<M> as WithTypeArg<TOut>>::Type
+    where
+        F: Fn(TIn) -> TOut,
+    {
+        Const(x.0, PhantomData)
+    }
+}
+
+impl<M: Monoid> Lift for ConstTypeCon<M> {
+    fn lift<T>(_x: T) -> <ConstTypeCon<M> as WithTypeArg<T>>::Type {
+        Const(M::default(), PhantomData)
+    }
+}
+
+impl<M: Monoid> LinearApplicative for ConstTypeCon<M> {
+    fn llift2<TIn1, TIn2, TOut, TFunc>(
+        _f: TFunc,
+        x1: <ConstTypeCon<M> as WithTypeArg<TIn1>>::Type,
+        x2: <ConstTypeCon<M> as WithTypeArg<TIn2>>::Type,
+    ) -> <ConstTypeCon<M> as WithTypeArg<TOut>>::Type
+    where
+        TFunc: FnOnce(TIn1, TIn2) -> TOut,
+    {
+        Const(x1.0.semigroup_op(x2.0), PhantomData)
+    }
+}
+
+// The monoid keeping the first value it's given, for `preview`.
+struct First<A>(Option<A>);
+
+impl<A> Default for First<A> {
+    fn default() -> Self {
+        First(None)
+    }
+}
+
+impl<A> Semigroup for First<A> {
+    fn semigroup_op(self, other: Self) -> Self {
+        First(self.0.or(other.0))
+    }
+}
+
+impl<A> Monoid for First<A> {}
@@ -1,8 +1,20 @@
+#![forbid(unsafe_code)]
+
 pub mod applicative;
+pub mod arrow;
+pub mod comonad;
+pub mod constrained;
+pub mod contravariant;
+pub mod control;
+pub mod eff;
+pub mod either;
 pub mod ext;
 pub mod functor;
+pub mod gat;
 pub mod impls;
+pub mod lens;
 pub mod monad;
+pub mod profunctor;
 pub mod typeapp;
 pub mod semigroup;
 pub mod monoid;
@@ -10,26 +22,36 @@ pub mod foldable;
 pub mod traversable;
 
 pub use applicative::*;
+pub use arrow::*;
+pub use comonad::*;
+pub use constrained::*;
+pub use contravariant::*;
+pub use control::*;
+pub use either::*;
 pub use ext::*;
 pub use functor::*;
 pub use impls::*;
 pub use monad::*;
+pub use profunctor::*;
 pub use typeapp::*;
 pub use semigroup::*;
 pub use monoid::*;
 pub use foldable::*;
 pub use traversable::*;
 
+#[cfg(feature = "derive")]
+pub use haskell_bits_derive::{Foldable, Functor, Traversable, TypeCon};
+
 #[doc(hidden)]
 pub mod mdo;
-#[doc(hidden)]
-pub use mdo::*;
+mod type_con;
 
 #[cfg(test)]
 mod tests {
     use crate::*;
 
     #[test]
+    #[allow(clippy::redundant_closure_call, clippy::clone_on_copy)]
     fn test() {
         // Shows both Vector and Option Functors are working
         let v: Vec<Option<u64>> = vec![Some(42), None];
@@ -115,6 +137,1786 @@ mod tests {
         assert_eq!(o2_result, None);
     }
 
+    #[test]
+    fn test_control() {
+        let halve = |x: &u32| if x.is_multiple_of(2) { Some(x / 2) } else { None };
+
+        assert_eq!(map_m(halve, &[2, 4, 6]), Some(vec![1, 2, 3]));
+        assert_eq!(map_m(halve, &[2, 3, 6]), None);
+        assert_eq!(for_m(&[1, 2], |x: &u32| vec![*x, x * 10]), vec![
+            vec![1, 2],
+            vec![1, 20],
+            vec![10, 2],
+            vec![10, 20]
+        ]);
+        assert_eq!(map_m_(halve, &[2, 4]), Some(()));
+        assert_eq!(replicate_m(2, &vec![0, 1]).len(), 4);
+        assert_eq!(replicate_m_(3, &Some(1)), Some(()));
+        assert_eq!(
+            filter_m(|_: &u32| vec![true, false], vec![1, 2]),
+            vec![vec![1, 2], vec![1], vec![2], vec![]]
+        );
+        assert_eq!(
+            fold_m(|acc: &u32, x: &u32| halve(x).map(|y| acc + y), 0, vec![2, 4]),
+            Some(3)
+        );
+        assert_eq!(
+            zip_with_m(|x: &u32, y: &u32| halve(&(x + y)), &[1, 2], &[3, 4]),
+            Some(vec![2, 3])
+        );
+        assert_eq!(when(true, &None::<()>), None);
+        assert_eq!(unless(true, &None::<()>), Some(()));
+        assert_eq!(void(&vec![1, 2]), vec![(), ()]);
+        assert_eq!(kleisli(halve, halve)(&12), Some(3));
+
+        let lhalve = |x: u32| -> Result<u32, u32> {
+            if x.is_multiple_of(2) {
+                Ok(x / 2)
+            } else {
+                Err(x)
+            }
+        };
+
+        assert_eq!(lmap_m(lhalve, vec![2, 4]), Ok(vec![1, 2]));
+        assert_eq!(lfor_m(vec![2, 3, 5], lhalve), Err(3));
+        assert_eq!(lmap_m_(lhalve, vec![2]), Ok(()));
+        assert_eq!(lfor_m_(vec![1], lhalve), Err(1));
+        assert_eq!(
+            lreplicate_m(2, Some(String::from("a"))),
+            Some(vec![String::from("a"); 2])
+        );
+        assert_eq!(lreplicate_m_(2, None::<u32>), None);
+        assert_eq!(lfilter_m(|x: &u32| Some(*x > 1), vec![1, 2, 3]), Some(vec![2, 3]));
+        assert_eq!(
+            lfold_m(|acc: u32, x: u32| lhalve(x).map(|y| acc + y), 0, vec![4, 8]),
+            Ok(6)
+        );
+        assert_eq!(lzip_with_m(|x: u32, y: u32| lhalve(x * y), vec![2], vec![3]), Ok(vec![3]));
+        assert_eq!(lzip_with_m_(|x: u32, y: u32| lhalve(x * y), vec![1], vec![3]), Err(3));
+        assert_eq!(lwhen(false, Err::<(), u32>(1)), Ok(()));
+        assert_eq!(lunless(false, Err::<(), u32>(1)), Err(1));
+        assert_eq!(lvoid(Some(5)), Some(()));
+        assert_eq!(lkleisli(lhalve, lhalve)(6), Err(3));
+    }
+
+    #[test]
+    fn test_applicative_utils() {
+        let add3 = |x: &u32, y: &u32, z: &u32| x + y + z;
+        let add6 = |a: &u32, b: &u32, c: &u32, d: &u32, e: &u32, f: &u32| a + b + c + d + e + f;
+
+        assert_eq!(lift3(add3, &Some(1), &Some(2), &Some(3)), Some(6));
+        assert_eq!(lift3(add3, &Some(1), &None, &Some(3)), None);
+        assert_eq!(lift3(add3, &vec![1, 2], &vec![10], &vec![100, 200]), vec![
+            111, 211, 112, 212
+        ]);
+        assert_eq!(
+            lift6(add6, &Ok(1), &Ok(2), &Err("c"), &Ok(4), &Err("e"), &Ok(6)),
+            Err("c")
+        );
+        let mul4 = |a: &u32, b: &u32, c: &u32, d: &u32| a * b * c * d;
+        assert_eq!(
+            lift4(mul4, &vec![1, 2], &vec![3], &vec![4], &vec![5]),
+            vec![60, 120]
+        );
+
+        // Option, Result and Vec don't need the values to be Clone
+        struct Meters(u32);
+        let total = |a: &Meters, b: &Meters, c: &Meters| a.0 + b.0 + c.0;
+        assert_eq!(
+            lift3(total, &Some(Meters(1)), &Some(Meters(2)), &Some(Meters(3))),
+            Some(6)
+        );
+        // Other applicatives copy them
+        assert_eq!(
+            lift3_clone(add3, &Identity(1), &Identity(2), &Identity(3)),
+            Identity(6)
+        );
+        assert_eq!(
+            lift4_clone(mul4, &Box::new(1), &Box::new(2), &Box::new(3), &Box::new(4)),
+            Box::new(24)
+        );
+
+        assert_eq!(seq_left(&Some(1), &Some("x")), Some(1));
+        assert_eq!(seq_right(&Some(1), &None::<u32>), None);
+        assert_eq!(ap_first(&vec![1, 2], &vec!['a', 'b']), vec![1, 1, 2, 2]);
+        assert_eq!(ap_second(&vec![1, 2], &vec!['a', 'b']), vec!['a', 'b', 'a', 'b']);
+        assert_eq!(zip_a(&Ok::<_, ()>(1), &Ok('a')), Ok((1, 'a')));
+
+        assert_eq!(
+            llift5(
+                |a: u32, b: u32, c: u32, d: u32, e: u32| a + b + c + d + e,
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(5)
+            ),
+            Some(15)
+        );
+        assert_eq!(
+            llift3(|a: u32, b: u32, c: u32| a + b + c, Ok(1), Err("b"), Err("c")),
+            Err("b")
+        );
+        assert_eq!(lseq_left(Ok::<_, &str>(1), Err::<u32, _>("e")), Err("e"));
+        assert_eq!(lseq_right(Some(1), Some(String::from("a"))), Some(String::from("a")));
+        assert_eq!(lzip_a(Some(1), Some('a')), Some((1, 'a')));
+    }
+
+    #[test]
+    fn test_linear_vec() {
+        let words = vec![String::from("a"), String::from("b")];
+        let suffixes = vec![String::from("1"), String::from("2")];
+
+        assert_eq!(
+            llift2_mut(|x: String, y: String| x + &y, words.clone(), suffixes.clone()),
+            vec!["a1", "a2", "b1", "b2"]
+        );
+
+        let fs: Vec<fn(String) -> usize> = vec![|x| x.len(), |x| x.len() * 10];
+        assert_eq!(lap_mut(fs, words.clone()), vec![1, 1, 10, 10]);
+
+        let mut calls = 0;
+        let doubled = lbind_mut(words.clone(), |x: String| {
+            calls += 1;
+            vec![x.clone(), x]
+        });
+        assert_eq!(doubled, vec!["a", "a", "b", "b"]);
+        assert_eq!(calls, 2);
+        assert_eq!(VecTypeCon::ljoin_mut(vec![vec![1], vec![2, 3]]), vec![1, 2, 3]);
+
+        let pairs: Vec<(String, String)> = ldo_mut! {
+            x =<< words;
+            y =<< suffixes.clone();
+            ret<VecTypeCon> (x.clone(), y);
+        };
+        assert_eq!(pairs.len(), 4);
+        assert_eq!(pairs[1], (String::from("a"), String::from("2")));
+
+        // Every LinearMonad is a LinearMonadMut too
+        let o: Option<(u32, u32)> = ldo_mut! {
+            x =<< Some(1);
+            y =<< Some(2);
+            ret<OptionTypeCon> (x, y);
+        };
+        assert_eq!(o, Some((1, 2)));
+    }
+
+    #[test]
+    fn test_result_foldable() {
+        let ok: Result<u32, String> = Ok(3);
+        let err: Result<u32, String> = Err(String::from("oops"));
+
+        assert_eq!(foldr(|x: &u32, acc| x + acc, 1, &ok), 4);
+        assert_eq!(foldr(|x: &u32, acc| x + acc, 1, &err), 1);
+        let collected = ok.foldr(vec![], |x: &u32, mut acc| {
+            acc.push(*x);
+            acc
+        });
+        assert_eq!(collected, vec![3]);
+        assert_eq!(lfoldr(|x: u32, acc| x + acc, 1, ok), 4);
+        assert_eq!(lfoldr(|x: u32, acc| x + acc, 1, err), 1);
+    }
+
+    #[test]
+    fn test_fjoin() {
+        let v: Vec<Vec<String>> = vec![vec![String::from("a")], vec![], vec![String::from("b")]];
+        assert_eq!(fjoin(&v), vec![String::from("a"), String::from("b")]);
+        assert_eq!(v.fjoin(), vec![String::from("a"), String::from("b")]);
+
+        assert_eq!(fjoin(&Some(Some(Box::new(1)))), Some(Box::new(1)));
+        assert_eq!(fjoin(&Some(None::<u32>)), None);
+        assert_eq!(fjoin(&None::<Option<u32>>), None);
+
+        let r: Result<Result<Vec<u32>, String>, String> = Ok(Ok(vec![1, 2]));
+        assert_eq!(fjoin(&r), Ok(vec![1, 2]));
+        let r: Result<Result<u32, String>, String> = Ok(Err(String::from("inner")));
+        assert_eq!(fjoin(&r), Err(String::from("inner")));
+        let r: Result<Result<u32, String>, String> = Err(String::from("outer"));
+        assert_eq!(fjoin(&r), Err(String::from("outer")));
+    }
+
+    #[test]
+    fn test_traverse() {
+        let halve = |x: u32| if x.is_multiple_of(2) { Some(x / 2) } else { None };
+
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(halve, vec![2, 4, 6]),
+            Some(vec![1, 2, 3])
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(halve, vec![2, 3]),
+            None
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(halve, Vec::new()),
+            Some(vec![])
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(halve, Some(8)),
+            Some(Some(4))
+        );
+
+        let v: Vec<Result<u32, String>> = vec![Ok(1), Err(String::from("a")), Err(String::from("b"))];
+        assert_eq!(sequence(v), Err(String::from("a")));
+        let r: Result<Option<u32>, String> = Ok(Some(1));
+        assert_eq!(sequence(r), Some(Ok(1)));
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Tree<T> {
+        Leaf(T),
+        Node(Box<Tree<T>>, Box<Tree<T>>),
+    }
+
+    type_con!(struct TreeTypeCon for<T> Tree<T>);
+
+    impl LinearFunctor for TreeTypeCon {
+        fn lmap<TIn, TOut, F>(
+            f: F,
+            x: <TreeTypeCon as WithTypeArg<TIn>>::Type,
+        ) -> <TreeTypeCon as WithTypeArg<TOut>>::Type
+        where
+            F: Fn(TIn) -> TOut,
+        {
+            fn go<TIn, TOut>(f: &impl Fn(TIn) -> TOut, x: Tree<TIn>) -> Tree<TOut> {
+                match x {
+                    Tree::Leaf(y) => Tree::Leaf(f(y)),
+                    Tree::Node(l, r) => Tree::Node(Box::new(go(f, *l)), Box::new(go(f, *r))),
+                }
+            }
+            go(&f, x)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Labelled<T, L> {
+        label: L,
+        value: T,
+    }
+
+    type_con!(struct LabelledTypeCon<L> for<T> Labelled<T, L>);
+
+    impl<L> LinearFunctor for LabelledTypeCon<L> {
+        fn lmap<TIn, TOut, F>(
+            f: F,
+            x: <LabelledTypeCon<L> as WithTypeArg<TIn>>::Type,
+        ) -> <LabelledTypeCon<L> as WithTypeArg<TOut>>::Type
+        where
+            F: Fn(TIn) -> TOut,
+        {
+            Labelled {
+                label: x.label,
+                value: f(x.value),
+            }
+        }
+    }
+
+    #[test]
+    fn test_type_con() {
+        let tree = Tree::Node(Box::new(Tree::Leaf(1)), Box::new(Tree::Leaf(2)));
+        assert_eq!(
+            lmap(|x: u32| x * 10, tree),
+            Tree::Node(Box::new(Tree::Leaf(10)), Box::new(Tree::Leaf(20)))
+        );
+
+        let labelled = Labelled {
+            label: "x",
+            value: 1,
+        };
+        assert_eq!(
+            lmap(|x: u32| x + 1, labelled),
+            Labelled {
+                label: "x",
+                value: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_gat() {
+        assert_eq!(
+            gat_map2::<VecTypeCon, _, _, _>(|x| x + 1, |x| x * 2, &vec![1, 2]),
+            vec![4, 6]
+        );
+        assert_eq!(
+            gat_map2::<OptionTypeCon, _, _, _>(|x| x + 1, |x| x * 2, &Some(1)),
+            Some(4)
+        );
+
+        assert_eq!(
+            gat_pair::<VecTypeCon, _>(&vec![1, 2], &vec![3]),
+            vec![(1, 3), (2, 3)]
+        );
+        assert_eq!(gat_pair::<ResultTypeCon<()>, _>(&Ok(1), &Err(())), Err(()));
+
+        assert_eq!(gat_sum::<VecTypeCon>(&vec![1, 2, 3]), 6);
+        assert_eq!(gat_sum::<OptionTypeCon>(&Some(4)), 4);
+
+        assert_eq!(
+            <VecTypeCon as gat::LinearTraversable>::traverse::<OptionTypeCon, _, _, _>(
+                Some,
+                vec![1, 2]
+            ),
+            Some(vec![1, 2])
+        );
+        assert_eq!(
+            <OptionTypeCon as gat::LinearTraversable>::sequence::<ResultTypeCon<()>, u32>(Some(Ok(1))),
+            Ok(Some(1))
+        );
+
+        // This goes to BTreeMap's own `sequence`, which keeps each value under its key.
+        let ports: std::collections::BTreeMap<&str, Option<u32>> =
+            vec![("http", Some(80)), ("https", Some(443))].into_iter().collect();
+        assert_eq!(
+            <BTreeMapTypeCon<&str> as gat::LinearTraversable>::sequence::<OptionTypeCon, u32>(ports)
+                .map(|m| m["https"]),
+            Some(443)
+        );
+    }
+
+    #[test]
+    fn test_smart_pointers() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        assert_eq!(
+            lmap(|x: String| x + "!", Box::new(String::from("hi"))),
+            Box::new(String::from("hi!"))
+        );
+        assert_eq!(lift2(|x: &u32, y: &u32| x + y, &Box::new(1), &Box::new(2)), Box::new(3));
+        assert_eq!(lbind(Box::new(2), |x: u32| Box::new(x * 3)), Box::new(6));
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| Some(x + 1), Box::new(1)),
+            Some(Box::new(2))
+        );
+
+        let shared = Rc::new(vec![1, 2]);
+        let kept = shared.clone();
+        assert_eq!(lmap_clone(|x: Vec<u32>| x.len(), shared), Rc::new(2));
+        assert_eq!(lmap_clone(|x: Vec<u32>| x.len(), kept), Rc::new(2));
+        assert_eq!(lift::<RcTypeCon, _>(1), Rc::new(1));
+        assert_eq!(fmap(|x: &u32| x + 1, &Rc::new(1)), Rc::new(2));
+        assert_eq!(bind(&Rc::new(1), |x: &u32| Rc::new(x * 2)), Rc::new(2));
+        let sum = mdo! {
+            x =<< &Rc::new(1);
+            y =<< &Rc::new(2);
+            ret<RcTypeCon> x + y;
+        };
+        assert_eq!(sum, Rc::new(3));
+
+        let arc = Arc::new(4);
+        assert_eq!(lift::<ArcTypeCon, _>(4), arc);
+        assert_eq!(lift2(|x: &u32, y: &u32| x * y, &arc, &arc), Arc::new(16));
+        assert_eq!(foldr(|x: &u32, acc: u32| x + acc, 1, &arc), 5);
+        assert_eq!(lmap_clone(|x: u32| x + 1, arc), Arc::new(5));
+    }
+
+    #[test]
+    fn test_sequences() {
+        use std::collections::{LinkedList, VecDeque};
+
+        let deque: VecDeque<u32> = vec![1, 2, 3].into();
+        assert_eq!(fmap(|x: &u32| x * 2, &deque), VecDeque::from(vec![2, 4, 6]));
+        assert_eq!(
+            lift2(|x: &u32, y: &u32| x * y, &deque, &VecDeque::from(vec![1, 10])),
+            VecDeque::from(vec![1, 10, 2, 20, 3, 30])
+        );
+        assert_eq!(
+            foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &deque),
+            vec![3, 2, 1]
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| Some(x + 1), deque),
+            Some(VecDeque::from(vec![2, 3, 4]))
+        );
+
+        let list: LinkedList<u32> = vec![1, 2].into_iter().collect();
+        assert_eq!(
+            lbind_mut(list.clone(), |x: u32| {
+                std::iter::repeat_n(x, x as usize).collect::<LinkedList<_>>()
+            }),
+            vec![1, 2, 2].into_iter().collect::<LinkedList<_>>()
+        );
+        assert_eq!(
+            lfoldr(|x: u32, acc: Vec<u32>| snoc(acc, x), Vec::new(), list.clone()),
+            vec![2, 1]
+        );
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(
+                |x: u32| if x > 1 { Ok(x) } else { Err(x) },
+                list
+            ),
+            Err(1)
+        );
+    }
+
+    #[test]
+    fn test_array() {
+        let rgb = [10u8, 20, 30];
+        assert_eq!(fmap(|x: &u8| x / 10, &rgb), [1, 2, 3]);
+        assert_eq!(lmap(|x: u8| u32::from(x) * 100, rgb), [1000, 2000, 3000]);
+        assert_eq!(
+            lift_f2(|x: &i32, y: &i32| x + y, &[1, 2, 3], &[10, 20, 30]),
+            [11, 22, 33]
+        );
+        assert_eq!(lift_f2(|x: &u32, y: &u32| x * y, &Some(2), &Some(3)), Some(6));
+        assert_eq!(
+            foldr(|x: &u8, acc: Vec<u8>| snoc(acc, *x), Vec::new(), &rgb),
+            vec![30, 20, 10]
+        );
+        assert_eq!(lfoldr(|x: u8, acc: u32| u32::from(x) + acc, 0, rgb), 60);
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u8| x.checked_mul(5), rgb),
+            Some([50, 100, 150])
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u8| x.checked_mul(10), rgb),
+            None
+        );
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(
+                |x: u8| if x < 20 { Ok(x) } else { Err(x) },
+                rgb
+            ),
+            Err(20)
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u8| Some(x), [0u8; 0]),
+            Some([])
+        );
+    }
+
+    #[test]
+    fn test_maps() {
+        use std::collections::{BTreeMap, HashMap};
+        use std::convert::TryFrom;
+
+        let ports: BTreeMap<&str, u32> = vec![("http", 80), ("https", 443)].into_iter().collect();
+        let strings = fmap(|x: &u32| x.to_string(), &ports);
+        assert_eq!(strings["https"], "443");
+        assert_eq!(
+            fmap_with_key(|k: &&str, v: &u32| format!("{}:{}", k, v), &ports)["http"],
+            "http:80"
+        );
+        assert_eq!(
+            foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &ports),
+            vec![443, 80]
+        );
+
+        let enabled: BTreeMap<&str, bool> =
+            vec![("https", true), ("ssh", true)].into_iter().collect();
+        let combined = lift_f2(|port: &u32, on: &bool| (*port, *on), &ports, &enabled);
+        assert_eq!(combined.into_iter().collect::<Vec<_>>(), vec![("https", (443, true))]);
+
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| u16::try_from(x).ok(), ports.clone())
+                .map(|m| m["http"]),
+            Some(80)
+        );
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(
+                |x: u32| if x < 100 { Ok(x) } else { Err(x) },
+                ports
+            ),
+            Err(443)
+        );
+
+        let mut config: HashMap<String, u32> = HashMap::new();
+        for i in 0..100 {
+            config.insert(format!("key{}", i), i);
+        }
+        let doubled = lmap(|x: u32| x * 2, config.clone());
+        assert_eq!(doubled["key21"], 42);
+        let checked =
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| Some(x + 1), config.clone()).unwrap();
+        assert!(checked.iter().all(|(k, v)| config[k] + 1 == *v));
+        assert_eq!(
+            lmap_with_key(|k: &String, v: u32| k.len() as u32 + v, config)["key5"],
+            9
+        );
+        assert_eq!(lfoldr(|x: u32, acc| x + acc, 0, doubled), 9900);
+    }
+
+    #[test]
+    fn test_constrained() {
+        use std::collections::{BTreeSet, HashSet};
+
+        // Each step of a search only keeps the distinct positions reached
+        let start: BTreeSet<i32> = clift::<BTreeSetTypeCon, _>(0);
+        let frontier = (0..10).fold(start, |frontier, _| {
+            cbind(&frontier, |x: &i32| -> BTreeSet<i32> {
+                vec![x - 1, x + 1].into_iter().collect()
+            })
+        });
+        assert_eq!(frontier.len(), 11);
+        assert_eq!(frontier.iter().next(), Some(&-10));
+
+        let frontier_ref = &frontier;
+        let sums: BTreeSet<i32> = cdo! {
+            x =<< frontier_ref;
+            y =<< frontier_ref;
+            ret<BTreeSetTypeCon> x + y;
+        };
+        assert_eq!(sums.len(), 21);
+
+        assert_eq!(
+            cmap(|x: &i32| x.abs(), &frontier).into_iter().collect::<Vec<_>>(),
+            vec![0, 2, 4, 6, 8, 10]
+        );
+        assert_eq!(
+            foldr(
+                |x: &i32, acc: Vec<i32>| snoc(acc, *x),
+                Vec::new(),
+                &cmap(|x: &i32| x / 4, &frontier)
+            ),
+            vec![2, 1, 0, -1, -2]
+        );
+
+        let words: HashSet<&str> = vec!["a", "bb", "cc"].into_iter().collect();
+        let lengths: HashSet<usize> = lcmap(|x: &str| x.len(), words.clone());
+        assert_eq!(lengths, vec![1, 2].into_iter().collect());
+        assert_eq!(clift2(|x: &&str, y: &usize| x.len() * y, &words, &lengths).len(), 3);
+        assert_eq!(set_pairs::<HashSetTypeCon<_>, _>(&lengths).len(), 4);
+    }
+
+    fn set_pairs<TCon, T>(
+        x: &<TCon as WithTypeArg<T>>::Type,
+    ) -> <TCon as WithTypeArg<(T, T)>>::Type
+    where
+        TCon: CMonad + Constrained<T> + Constrained<(T, T)> + WithTypeArg<T> + WithTypeArg<(T, T)>,
+        T: Clone,
+    {
+        cbind::<TCon, _, _, _, _, _>(x, |y1: &T| {
+            cmap::<TCon, _, _, _, _>(|y2: &T| (y1.clone(), y2.clone()), x)
+        })
+    }
+
+    #[test]
+    fn test_zip_vec() {
+        let xs = ZipVec::from(vec![1, 2, 3]);
+        let ys = ZipVec::from(vec![10, 20]);
+        assert_eq!(lift2(|x: &u32, y: &u32| x + y, &xs, &ys), ZipVec::from(vec![11, 22]));
+        assert_eq!(
+            lift2(|x: &u32, y: &u32| x * y, &xs, &lift::<ZipVecTypeCon, _>(2)),
+            ZipVec::from(vec![2, 4, 6])
+        );
+        assert_eq!(
+            ap(&lift::<ZipVecTypeCon, _>(|x: &u32| x + 1), &lift::<ZipVecTypeCon, _>(1)),
+            ZipVec::Repeat(2)
+        );
+        assert_eq!(
+            llift2_mut(
+                |x: String, y: u32| format!("{}{}", x, y),
+                ZipVec::Repeat(String::from("x")),
+                xs.clone()
+            ),
+            ZipVec::from(vec![String::from("x1"), String::from("x2"), String::from("x3")])
+        );
+
+        // The same generic code runs in zip mode or in cartesian product mode
+        assert_eq!(zip_a(&xs, &ys), ZipVec::from(vec![(1, 10), (2, 20)]));
+        assert_eq!(zip_a(&vec![1, 2], &vec![10]), vec![(1, 10), (2, 10)]);
+
+        // Only a finite ZipVec can be folded or traversed
+        assert_eq!(lift::<ZipVecTypeCon, _>(1).finite(), None);
+        let finite = xs.finite().unwrap();
+        assert_eq!(
+            foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &finite),
+            vec![3, 2, 1]
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| Some(x * 2), finite)
+                .map(ZipVec::from),
+            Some(ZipVec::from(vec![2, 4, 6]))
+        );
+    }
+
+    #[test]
+    fn test_non_empty() {
+        let xs = NonEmpty::new(3, vec![1, 4, 1, 5]);
+        assert_eq!(NonEmpty::from_vec(Vec::<u32>::new()), None);
+        assert_eq!(NonEmpty::from_vec(vec![3, 1, 4, 1, 5]), Some(xs.clone()));
+
+        assert_eq!(*head(&xs), 3);
+        assert_eq!(*last(&xs), 5);
+        assert_eq!(*maximum1(&xs), 5);
+        assert_eq!(*minimum1(&xs), 1);
+        assert_eq!(fold1(|x: u32, y: u32| x.max(y) * 10 + x.min(y), NonEmpty::singleton(7)), 7);
+        assert_eq!(fold1(|x: i32, y: i32| x - y, xs.clone()), 3 - (1 - (4 - (1 - 5))));
+
+        // sconcat uses the element type's Semigroup instance
+        let nested = NonEmpty::new(NonEmpty::singleton(1), vec![NonEmpty::new(2, vec![3])]);
+        assert_eq!(sconcat(nested), NonEmpty::new(1, vec![2, 3]));
+
+        let ys = NonEmpty::new(10, vec![20]);
+        assert_eq!(fmap(|x: &u32| x * 2, &ys), NonEmpty::new(20, vec![40]));
+        assert_eq!(
+            lift2(|x: &u32, y: &u32| x + y, &NonEmpty::new(1, vec![2]), &ys),
+            NonEmpty::new(11, vec![21, 12, 22])
+        );
+        assert_eq!(
+            bind(&ys, |x: &u32| NonEmpty::new(*x, vec![x + 1])),
+            NonEmpty::new(10, vec![11, 20, 21])
+        );
+        assert_eq!(
+            lbind_mut(ys.clone(), |x: u32| NonEmpty::singleton(x.to_string())),
+            NonEmpty::new(String::from("10"), vec![String::from("20")])
+        );
+        assert_eq!(
+            foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &ys),
+            vec![20, 10]
+        );
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(
+                |x: u32| if x > 0 { Ok(x) } else { Err(x) },
+                ys.clone()
+            ),
+            Ok::<_, u32>(ys)
+        );
+    }
+
+    #[test]
+    fn test_comonad() {
+        assert_eq!(extract(&Identity(3)), 3);
+        assert_eq!(extend(|x: &Identity<u32>| x.0 + 1, &Identity(3)), Identity(4));
+        assert_eq!(lextract(Identity(String::from("x"))), "x");
+
+        // The context of each element of a NonEmpty is its suffix
+        let xs = NonEmpty::new(1, vec![2, 3]);
+        assert_eq!(extract(&xs), 1);
+        assert_eq!(
+            extend(|ys: &NonEmpty<u32>| ys.iter().sum::<u32>(), &xs),
+            NonEmpty::new(6, vec![5, 3])
+        );
+        assert_eq!(
+            duplicate(&xs),
+            NonEmpty::new(xs.clone(), vec![NonEmpty::new(2, vec![3]), NonEmpty::singleton(3)])
+        );
+        assert_eq!(lduplicate(xs.clone()), duplicate(&xs));
+        assert_eq!(lextend(|ys: NonEmpty<u32>| ys.len(), xs), NonEmpty::new(3, vec![2, 1]));
+
+        // Env reads its environment alongside the value
+        let scaled = extend(|(scale, x): &(u32, u32)| scale * x, &(10, 4));
+        assert_eq!(scaled, (10, 40));
+        assert_eq!(lextract(lmap(|x: u32| x + 1, scaled)), 41);
+
+        // One step of the rule 90 cellular automaton, over cells with dead cells off the edges
+        let cells = [false, false, true, false, false];
+        let row = Store::tabulate(0..cells.len() as i32, |i| cells[*i as usize], 0).unwrap();
+        let rule_90 = |w: &Store<i32, bool>| {
+            let alive = |i| *w.peek(&i).unwrap_or(&false);
+            alive(w.pos() - 1) != alive(w.pos() + 1)
+        };
+        let next = extend(rule_90, &row);
+        assert_eq!(
+            next.positions().map(|i| *next.peek(i).unwrap()).collect::<Vec<_>>(),
+            vec![false, true, false, true, false]
+        );
+        assert!(extract(&next.seek(1).unwrap()));
+        assert_eq!(row.seek(5), None);
+        let labels = lmap(|x: bool| if x { "alive" } else { "dead" }, next);
+        assert_eq!(labels.peek(&3), Some(&"alive"));
+        let neighbourhoods = lduplicate(labels.clone());
+        assert_eq!(neighbourhoods.peek(&3).map(Store::pos), Some(&3));
+        assert_eq!(lextract(lextract(neighbourhoods)), lextract(labels));
+
+        // A traced comonad over the integers mod 4, taking each value's difference from the next
+        let traced = Traced::new((0..4).map(|m| (Mod4(m), u32::from(m) * u32::from(m))).collect())
+            .unwrap();
+        assert_eq!(extract(&traced), 0);
+        assert_eq!(traced.trace(Mod4(3)), Some(&9));
+        let diffs = extend(
+            |t: &Traced<Mod4, u32>| *t.trace(Mod4(1)).unwrap() as i32 - extract(t) as i32,
+            &traced,
+        );
+        assert_eq!(
+            (0..4).map(|m| *diffs.trace(Mod4(m)).unwrap()).collect::<Vec<_>>(),
+            vec![1, 3, 5, -9]
+        );
+        assert_eq!(lextract(lextend(|t: Traced<Mod4, i32>| lextract(t) * 2, diffs)), 2);
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+    struct Mod4(u8);
+
+    impl Semigroup for Mod4 {
+        fn semigroup_op(self, other: Self) -> Self {
+            Mod4((self.0 + other.0) % 4)
+        }
+    }
+
+    impl Monoid for Mod4 {}
+
+    #[test]
+    fn test_zippers() {
+        let mut xs = VecZipper::from_vec(vec![1, 2, 3, 4]).unwrap();
+        assert!(!xs.left());
+        assert!(xs.right() && xs.right());
+        assert_eq!((*xs.focus(), xs.position()), (3, 2));
+        assert_eq!((xs.peek_left(), xs.peek_right()), (Some(&2), Some(&4)));
+        xs.modify(|x| *x *= 10);
+        xs.insert_left(25);
+        assert_eq!(xs.remove(), Some(30));
+        assert_eq!(xs.clone().into_vec(), vec![1, 2, 25, 4]);
+        assert_eq!(xs, VecZipper::new(vec![1, 2, 25], 4, vec![]));
+
+        // A blur, averaging each element with its neighbours
+        let blurred = extend(
+            |z: &VecZipper<u32>| {
+                let around = [z.peek_left(), Some(z.focus()), z.peek_right()];
+                let present = around.iter().flatten();
+                present.clone().copied().sum::<u32>() / present.count() as u32
+            },
+            &xs,
+        );
+        assert_eq!(blurred.clone().into_vec(), vec![1, 9, 10, 14]);
+        assert_eq!((extract(&blurred), blurred.position()), (14, 3));
+        assert_eq!(lextract(lduplicate(xs.clone())), xs);
+        assert_eq!(
+            foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &fmap(|x: &u32| x + 1, &xs)),
+            vec![5, 26, 3, 2]
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| x.checked_sub(2), xs),
+            None
+        );
+        let ys = VecZipper::new(vec![1], 2, vec![3, 4]);
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(
+                |x: u32| if x < 3 { Ok(x) } else { Err(x) },
+                ys.clone()
+            ),
+            Err(3)
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| Some(x * 2), ys),
+            Some(VecZipper::new(vec![2], 4, vec![6, 8]))
+        );
+
+        let tree = crate::Tree::new(
+            1,
+            vec![
+                crate::Tree::new(2, vec![crate::Tree::leaf(3), crate::Tree::leaf(4)]),
+                crate::Tree::leaf(5),
+            ],
+        );
+        let mut zipper = TreeZipper::new(tree.clone());
+        assert!(!zipper.up() && !zipper.right() && !zipper.down(2));
+        assert!(zipper.down(0) && zipper.down(1));
+        assert_eq!((*zipper.value(), zipper.depth(), zipper.path()), (4, 2, vec![0, 1]));
+        assert!(zipper.left() && !zipper.left());
+        zipper.set_value(30);
+
+        // Folds see the whole tree in pre-order, wherever the focus is
+        assert_eq!(
+            foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &zipper),
+            vec![5, 4, 30, 2, 1]
+        );
+
+        // The size of the subtree at each node
+        let sizes = extend(
+            |z: &TreeZipper<u32>| foldr(|_: &u32, n: usize| n + 1, 0, &TreeZipper::new(z.focus().clone())),
+            &zipper,
+        );
+        assert_eq!(extract(&sizes), 1);
+        assert_eq!(sizes.path(), zipper.path());
+        assert_eq!(lextract(lduplicate(zipper.clone())), zipper);
+        assert_eq!(lextract(lextend(|z: TreeZipper<u32>| z.depth(), zipper.clone())), 2);
+        assert_eq!(
+            sizes.into_tree(),
+            crate::Tree::new(
+                5,
+                vec![
+                    crate::Tree::new(3, vec![crate::Tree::leaf(1), crate::Tree::leaf(1)]),
+                    crate::Tree::leaf(1),
+                ]
+            )
+        );
+
+        assert_eq!(zipper.remove(), Some(crate::Tree::leaf(30)));
+        zipper.insert_child(crate::Tree::leaf(6));
+        assert_eq!(
+            lmap(|x: u32| x * 10, zipper).into_tree(),
+            crate::Tree::new(
+                10,
+                vec![
+                    crate::Tree::new(20, vec![crate::Tree::leaf(40), crate::Tree::leaf(60)]),
+                    crate::Tree::leaf(50),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_trees() {
+        // Each number's children are the halves of its even divisors
+        let tree = unfold_tree(|n: u32| (n, (1..n).filter(|d| n.is_multiple_of(d * 2)).collect()), 4);
+        assert_eq!(
+            tree,
+            crate::Tree::new(4, vec![crate::Tree::new(1, vec![]), crate::Tree::new(2, vec![crate::Tree::leaf(1)])])
+        );
+        assert_eq!(tree.preorder().copied().collect::<Vec<_>>(), vec![4, 1, 2, 1]);
+        assert_eq!(tree.postorder().copied().collect::<Vec<_>>(), vec![1, 1, 2, 4]);
+        assert_eq!(tree.clone().flatten(), vec![4, 1, 2, 1]);
+        assert_eq!(
+            tree.foldr_postorder(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new()),
+            vec![4, 2, 1, 1]
+        );
+        assert_eq!(
+            tree.clone()
+                .lfoldr_postorder(|x: u32, acc: Vec<u32>| snoc(acc, x), Vec::new()),
+            vec![4, 2, 1, 1]
+        );
+        // The first error in each order
+        let big = |x: u32| if x > 1 { Err(x) } else { Ok(x) };
+        assert_eq!(traverse::<_, ResultTypeCon<_>, _, _, _, _>(big, tree.clone()), Err(4));
+        assert_eq!(tree.clone().traverse_postorder::<ResultTypeCon<_>, _, _>(big), Err(2));
+        assert_eq!(
+            tree.clone()
+                .traverse_postorder::<OptionTypeCon, _, _>(|x: u32| x.checked_sub(1)),
+            Some(crate::Tree::new(
+                3,
+                vec![
+                    crate::Tree::leaf(0),
+                    crate::Tree::new(1, vec![crate::Tree::leaf(0)])
+                ]
+            ))
+        );
+        assert_eq!(foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &tree), vec![1, 2, 1, 4]);
+
+        let small = crate::Tree::new(1, vec![crate::Tree::leaf(2)]);
+        let tens = crate::Tree::new(10, vec![crate::Tree::leaf(20)]);
+        assert_eq!(
+            lift2(|x: &u32, y: &u32| x + y, &small, &tens),
+            crate::Tree::new(11, vec![crate::Tree::leaf(21), crate::Tree::new(12, vec![crate::Tree::leaf(22)])])
+        );
+        assert_eq!(
+            lift2(|x: &u32, y: &u32| x + y, &small, &tens),
+            bind(&small, |x: &u32| fmap(|y: &u32| x + y, &tens))
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| x.checked_sub(1), tree.clone())
+                .map(crate::Tree::flatten),
+            Some(vec![3, 0, 1, 0])
+        );
+        assert_eq!(
+            traverse::<_, OptionTypeCon, _, _, _, _>(|x: u32| x.checked_sub(2), tree),
+            None
+        );
+
+        // A balanced search tree of 1 to 6
+        let search = unfold_bin_tree(
+            |(lo, hi): (i32, i32)| {
+                let mid = (lo + hi) / 2;
+                if lo > hi {
+                    None
+                } else {
+                    Some(((lo, mid - 1), mid as u32, (mid + 1, hi)))
+                }
+            },
+            (1, 6),
+        );
+        assert_eq!(search.inorder().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(search.preorder().copied().collect::<Vec<_>>(), vec![3, 1, 2, 5, 4, 6]);
+        assert_eq!(search.postorder().copied().collect::<Vec<_>>(), vec![2, 1, 4, 6, 5, 3]);
+        assert_eq!(
+            search.foldr_preorder(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new()),
+            vec![6, 4, 5, 2, 1, 3]
+        );
+        assert_eq!(
+            search.foldr_postorder(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new()),
+            vec![3, 5, 6, 4, 1, 2]
+        );
+        assert_eq!(
+            search
+                .clone()
+                .lfoldr_postorder(|x: u32, acc: Vec<u32>| snoc(acc, x), Vec::new()),
+            vec![3, 5, 6, 4, 1, 2]
+        );
+        // The first error in each order
+        let big = |x: u32| if x > 3 { Err(x) } else { Ok(x) };
+        let triple = |x: u32| if x.is_multiple_of(3) { Err(x) } else { Ok(x) };
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(big, search.clone()),
+            Err(4)
+        );
+        assert_eq!(
+            search
+                .clone()
+                .traverse_preorder::<ResultTypeCon<_>, _, _>(big),
+            Err(5)
+        );
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(triple, search.clone()),
+            Err(3)
+        );
+        assert_eq!(
+            search
+                .clone()
+                .traverse_postorder::<ResultTypeCon<_>, _, _>(triple),
+            Err(6)
+        );
+        assert_eq!(
+            search
+                .clone()
+                .traverse_postorder::<OptionTypeCon, _, _>(|x: u32| x.checked_sub(1))
+                .map(crate::BinTree::flatten),
+            Some(vec![0, 1, 2, 3, 4, 5])
+        );
+        assert_eq!(
+            foldr(|x: &u32, acc: Vec<u32>| snoc(acc, *x), Vec::new(), &search),
+            vec![6, 5, 4, 3, 2, 1]
+        );
+
+        assert_eq!(
+            traverse::<_, ResultTypeCon<_>, _, _, _, _>(
+                |x: u32| if x < 7 { Ok(x) } else { Err(x) },
+                search.clone()
+            ),
+            Ok::<_, u32>(search)
+        );
+    }
+
+    #[test]
+    fn test_contravariant() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Person {
+            name: &'static str,
+            age: u32,
+        }
+        let people = vec![
+            Person { name: "b", age: 30 },
+            Person { name: "a", age: 40 },
+            Person { name: "c", age: 30 },
+        ];
+        let names = |xs: &[Person]| xs.iter().map(|p| p.name).collect::<Vec<_>>();
+
+        // Sort by age, oldest first, and then by name
+        let by_age = contramap(|p: &Person| p.age, Comparison::by_ord()).reverse();
+        let comparison = divide(
+            |p: &Person| (p.clone(), p.name),
+            by_age,
+            Comparison::<&str>::by_ord(),
+        );
+        let mut sorted = people.clone();
+        sorted.sort_by(|x, y| comparison.compare(x, y));
+        assert_eq!(names(&sorted), vec!["a", "b", "c"]);
+
+        let same_age = contramap(|p: &Person| p.age, Equivalence::by_eq());
+        assert!(same_age.equivalent(&people[0], &people[2]));
+        assert!(!same_age.equivalent(&people[0], &people[1]));
+
+        let adult = contramap(|p: &Person| p.age, Predicate::new(|age: &u32| *age >= 18));
+        let short_name = contramap(|p: &Person| p.name, Predicate::new(|name: &&str| name.len() < 2));
+        let valid = divide(|p: &Person| (p.clone(), p.clone()), adult, short_name);
+        assert!(people.iter().all(|p| valid.test(p)));
+        assert!(!valid.test(&Person { name: "d", age: 10 }));
+        assert!(conquer::<PredicateTypeCon, Person>().test(&people[0]));
+
+        // Collecting every validation error, rather than stopping at the first
+        let check = |message: &'static str, f: fn(&Person) -> bool| {
+            Op::new(move |p: &Person| if f(p) { Vec::new() } else { vec![message] })
+        };
+        let errors = divide(
+            |p: &Person| (p.clone(), p.clone()),
+            check("too young", |p| p.age >= 18),
+            check("no name", |p| !p.name.is_empty()),
+        );
+        assert_eq!(errors.run(&people[0]), Vec::<&str>::new());
+        assert_eq!(errors.run(&Person { name: "", age: 10 }), vec!["too young", "no name"]);
+
+        // Numbers come before words, and are compared numerically
+        let parsed = |x: &&str| match x.parse::<u32>() {
+            Ok(n) => Either::Left(n),
+            Err(_) => Either::Right(x.to_string()),
+        };
+        let mixed = choose(parsed, Comparison::<u32>::by_ord(), Comparison::<String>::by_ord());
+        let mut values = vec!["b", "10", "a", "9"];
+        values.sort_by(|x, y| mixed.compare(x, y));
+        assert_eq!(values, vec!["9", "10", "a", "b"]);
+    }
+
+    #[test]
+    fn test_profunctor() {
+        let double: Func<u32, u32> = Box::new(|x| x * 2);
+        let shown = dimap(|s: &str| s.len() as u32, |x: u32| x.to_string(), double);
+        assert_eq!(shown("abc"), "6");
+        let shown_first = first::<FuncTypeCon, _, _, bool, _>(shown);
+        assert_eq!(shown_first(("ab", true)), (String::from("4"), true));
+        let shown_second = second::<FuncTypeCon, _, _, u8, _>(rmap_p(
+            |s: String| s + "!",
+            Box::new(|x: u32| x.to_string()) as Func<u32, String>,
+        ));
+        assert_eq!(shown_second((1, 5)), (1, String::from("5!")));
+        let negated: Func<Either<i32, &str>, Either<i32, &str>> =
+            left(Box::new(|x: i32| -x) as Func<i32, i32>);
+        assert_eq!(negated(Either::Left(3)), Either::Left(-3));
+        assert_eq!(negated(Either::Right("x")), Either::Right("x"));
+
+        // Kleisli arrows run their monad's effects
+        let parse = Kleisli::<OptionTypeCon, &str, u32>::new(|s| s.parse().ok());
+        let parse_len = lmap_p(|s: String| if s.is_empty() { "" } else { "12" }, parse);
+        assert_eq!(parse_len.run(String::from("x")), Some(12));
+        assert_eq!(parse_len.run(String::new()), None);
+        let parse = Kleisli::<OptionTypeCon, &str, u32>::new(|s| s.parse().ok());
+        let parse_tagged = first::<KleisliTypeCon<OptionTypeCon>, _, _, char, _>(parse);
+        assert_eq!(parse_tagged.run(("7", 'x')), Some((7, 'x')));
+        let parse = Kleisli::<OptionTypeCon, &str, u32>::new(|s| s.parse().ok());
+        let parse_either = right::<KleisliTypeCon<OptionTypeCon>, _, _, bool, _>(parse);
+        assert_eq!(parse_either.run(Either::Right("8")), Some(Either::Right(8)));
+        assert_eq!(
+            parse_either.run(Either::Left(true)),
+            Some(Either::Left(true))
+        );
+        assert_eq!(parse_either.run(Either::Right("?")), None);
+
+        let neighbours = Kleisli::<VecTypeCon, i32, i32>::new(|x| vec![x - 1, x + 1]);
+        let labelled = first::<KleisliTypeCon<VecTypeCon>, _, _, String, _>(neighbours);
+        assert_eq!(
+            labelled.run((5, String::from("a"))),
+            vec![(4, String::from("a")), (6, String::from("a"))]
+        );
+
+        // Forget gets values out, and Tagged puts them in
+        let get_name = Forget::<String, (String, u32), ()>::new(|(name, _)| name);
+        let get_from_left = left::<ForgetTypeCon<String>, _, _, u32, _>(get_name);
+        assert_eq!(get_from_left.run(Either::Left((String::from("a"), 1))), "a");
+        assert_eq!(get_from_left.run(Either::Right(2)), "");
+        let tagged = dimap(|_: u8| (), |x: u32| x + 1, Tagged::<(), u32>::new(1));
+        assert_eq!(tagged, Tagged::new(2));
+        assert_eq!(
+            left::<TaggedTypeCon, _, _, u8, _>(tagged).0,
+            Either::Left(2)
+        );
+    }
+
+    #[test]
+    fn test_arrow() {
+        // Plain functions, described point free
+        let add_one: Func<i32, i32> = arr::<FuncTypeCon, _, _, _>(|x: i32| x + 1);
+        let double: Func<i32, i32> = Box::new(|x| x * 2);
+        let add_then_double = and_then(add_one, double);
+        assert_eq!(add_then_double(3), 8);
+        let double_then_add = compose(
+            Box::new(|x: i32| x + 1) as Func<i32, i32>,
+            Box::new(|x: i32| x * 2) as Func<i32, i32>,
+        );
+        assert_eq!(double_then_add(3), 7);
+        assert_eq!(id::<FuncTypeCon, &str>()("a"), "a");
+
+        let both = split(
+            add_then_double,
+            Box::new(|s: &str| s.len()) as Func<&str, usize>,
+        );
+        assert_eq!(both((1, "abc")), (4, 3));
+        let stats = fanout(
+            Box::new(|xs: Vec<i32>| xs.iter().sum::<i32>()) as Func<Vec<i32>, i32>,
+            Box::new(|xs: Vec<i32>| xs.len()) as Func<Vec<i32>, usize>,
+        );
+        assert_eq!(stats(vec![1, 2, 3]), (6, 3));
+
+        let describe = fanin(
+            Box::new(|x: i32| format!("int {}", x)) as Func<i32, String>,
+            Box::new(|s: &str| format!("str {}", s)) as Func<&str, String>,
+        );
+        assert_eq!(describe(Either::Left(1)), "int 1");
+        assert_eq!(describe(Either::Right("a")), "str a");
+        let either_len = split_either(
+            Box::new(|x: i32| -x) as Func<i32, i32>,
+            Box::new(|s: &str| s.len()) as Func<&str, usize>,
+        );
+        assert_eq!(either_len(Either::Right("ab")), Either::Right(2));
+
+        // A small pipeline over Result, which stops at the first failure
+        type Step<A, B> = Kleisli<ResultTypeCon<String>, A, B>;
+        let parse = Step::<&str, i32>::new(|s| s.parse().map_err(|_| format!("bad number {}", s)));
+        let positive = Step::<i32, i32>::new(|x| {
+            if x > 0 {
+                Ok(x)
+            } else {
+                Err(format!("{} isn't positive", x))
+            }
+        });
+        let halve = arr::<KleisliTypeCon<ResultTypeCon<String>>, _, _, _>(|x: i32| x / 2);
+        let pipeline = and_then(and_then(parse, positive), halve);
+        assert_eq!(pipeline.run("10"), Ok(5));
+        assert_eq!(pipeline.run("-1"), Err(String::from("-1 isn't positive")));
+        assert_eq!(pipeline.run("x"), Err(String::from("bad number x")));
+
+        let checked = fanout(
+            pipeline,
+            id::<KleisliTypeCon<ResultTypeCon<String>>, &str>(),
+        );
+        assert_eq!(checked.run("4"), Ok((2, "4")));
+
+        // Kleisli arrows over Vec compose nondeterministic steps
+        let neighbours = Kleisli::<VecTypeCon, i32, i32>::new(|x| vec![x - 1, x + 1]);
+        let two_steps = compose(
+            Kleisli::<VecTypeCon, i32, i32>::new(|x| vec![x - 1, x + 1]),
+            neighbours,
+        );
+        assert_eq!(two_steps.run(0), vec![-2, 0, 0, 2]);
+
+        // Every monad has Kleisli arrows, including trees
+        let grow = Kleisli::<crate::TreeTypeCon, i32, i32>::new(|x| {
+            crate::Tree::new(x, vec![crate::Tree::leaf(x * 10)])
+        });
+        let grow_twice = compose(
+            Kleisli::<crate::TreeTypeCon, i32, i32>::new(|x| {
+                crate::Tree::new(x, vec![crate::Tree::leaf(x * 10)])
+            }),
+            grow,
+        );
+        assert_eq!(
+            grow_twice.run(1),
+            crate::Tree::new(
+                1,
+                vec![
+                    crate::Tree::leaf(10),
+                    crate::Tree::new(10, vec![crate::Tree::leaf(100)])
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_lens() {
+        use crate::lens::*;
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Server {
+            host: String,
+            port: u16,
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Config {
+            name: String,
+            servers: Vec<Server>,
+            backup: Option<Server>,
+        }
+
+        let server = |host: &str, port| Server {
+            host: String::from(host),
+            port,
+        };
+        let config = Config {
+            name: String::from("prod"),
+            servers: vec![server("a", 80), server("b", 8080)],
+            backup: Some(server("c", 0)),
+        };
+
+        // Updates move the config apart and back together, rather than copying it
+        let name = split_lens(
+            |c: &Config| c.name.clone(),
+            |mut c: Config| (std::mem::take(&mut c.name), c),
+            |c: Config, name| Config { name, ..c },
+        );
+        let servers = split_lens(
+            |c: &Config| c.servers.clone(),
+            |mut c: Config| (std::mem::take(&mut c.servers), c),
+            |c: Config, servers| Config { servers, ..c },
+        );
+        let backup = split_lens(
+            |c: &Config| c.backup.clone(),
+            |mut c: Config| (c.backup.take(), c),
+            |c: Config, backup| Config { backup, ..c },
+        );
+        let port = split_lens(
+            |s: &Server| s.port,
+            |s: Server| (s.port, s),
+            |s: Server, port| Server { port, ..s },
+        );
+        let host = split_lens(
+            |s: &Server| s.host.clone(),
+            |mut s: Server| (std::mem::take(&mut s.host), s),
+            |s: Server, host| Server { host, ..s },
+        );
+
+        assert_eq!(view(&name, &config), "prod");
+        assert_eq!(
+            set(&name, String::from("test"), config.clone()).name,
+            "test"
+        );
+
+        // Lenses compose with traversals through Vec and Option fields
+        let server_ports = servers
+            .then(traversed::<VecTypeCon, _, _>())
+            .then(port.clone());
+        let backup_port = backup
+            .then(traversed::<OptionTypeCon, _, _>())
+            .then(port.clone());
+        assert_eq!(to_list_of(&server_ports, config.clone()), vec![80, 8080]);
+        assert_eq!(preview(&backup_port, config.clone()), Some(0));
+        assert_eq!(
+            preview(
+                &backup_port,
+                Config {
+                    backup: None,
+                    ..config.clone()
+                }
+            ),
+            None
+        );
+
+        let bumped = over(&server_ports, |p| p + 1, config.clone());
+        assert_eq!(bumped.servers, vec![server("a", 81), server("b", 8081)]);
+        assert_eq!(bumped.backup, config.backup);
+        let no_backup_port = set(&backup_port, 443, config.clone());
+        assert_eq!(no_backup_port.backup, Some(server("c", 443)));
+
+        // An update which can fail, failing the whole update
+        let check = |p: u16| {
+            if p == 0 {
+                Err(String::from("port 0"))
+            } else {
+                Ok(p)
+            }
+        };
+        assert_eq!(
+            traverse_of(&server_ports, check, config.clone()),
+            Ok(config.clone())
+        );
+        assert_eq!(
+            traverse_of(&backup_port, check, config.clone()),
+            Err(String::from("port 0"))
+        );
+
+        // A lens from a getter and a setter builds the new whole from a borrow of the old one
+        let first = lens(|p: &(u16, u16)| p.0, |p: &(u16, u16), x| (x, p.1));
+        assert_eq!(over(&first, |x| x + 1, (1, 2)), (2, 2));
+        assert_eq!(
+            first.lens_of::<IdentityTypeCon, _>(|x| Identity(x * 3), (1, 2)),
+            Identity((3, 2))
+        );
+        assert_eq!(
+            port.lens_of::<IdentityTypeCon, _>(|p| Identity(p + 1), server("a", 1)),
+            Identity(server("a", 2))
+        );
+
+        // Getters only read, and compose with lenses
+        let host_len = host.then(to(|h: &String| h.len()));
+        assert_eq!(view(&host_len, &server("abc", 1)), 3);
+
+        // Prisms focus on one alternative
+        let ok = prism(|r: Result<u16, String>| r.map_err(Err), Ok);
+        assert_eq!(preview(&ok, Ok(1)), Some(1));
+        assert_eq!(preview(&ok, Err(String::from("e"))), None);
+        assert_eq!(over(&ok, |p| p * 2, Ok(2)), Ok(4));
+        assert_eq!(review(&ok, 3), Ok(3));
+
+        // Isos convert between representations, and are lenses and prisms
+        let tenths = iso(
+            |x: &f64| (x * 10.0).round() as i64,
+            |x: i64| x as f64 / 10.0,
+        );
+        assert_eq!(view(&tenths, &1.5), 15);
+        assert_eq!(over(&tenths, |x| x + 1, 1.5), 1.6);
+        assert_eq!(review(&tenths, 25), 2.5);
+        let ok_tenths = ok.then(iso(|x: &u16| *x as i64, |x: i64| x as u16));
+        assert_eq!(review(&ok_tenths, 7), Ok(7));
+    }
+
+    #[test]
+    fn test_free() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        // The instructions of a tiny banking workflow
+        enum Cmd<Next> {
+            Log(String, Next),
+            Fail(String),
+        }
+
+        type_con!(struct CmdTypeCon for<Next> Cmd<Next>);
+
+        impl Functor for CmdTypeCon {
+            fn fmap<TIn, TOut, F>(
+                f: F,
+                x: &<CmdTypeCon as WithTypeArg<TIn>>::Type,
+            ) -> <CmdTypeCon as WithTypeArg<TOut>>::Type
+            where
+                F: Fn(&TIn) -> TOut,
+            {
+                match x {
+                    Cmd::Log(msg, next) => Cmd::Log(msg.clone(), f(next)),
+                    Cmd::Fail(msg) => Cmd::Fail(msg.clone()),
+                }
+            }
+        }
+
+        impl LinearFunctor for CmdTypeCon {
+            fn lmap<TIn, TOut, F>(
+                f: F,
+                x: <CmdTypeCon as WithTypeArg<TIn>>::Type,
+            ) -> <CmdTypeCon as WithTypeArg<TOut>>::Type
+            where
+                F: Fn(TIn) -> TOut,
+            {
+                match x {
+                    Cmd::Log(msg, next) => Cmd::Log(msg, f(next)),
+                    Cmd::Fail(msg) => Cmd::Fail(msg),
+                }
+            }
+        }
+
+        type Program<A> = Free<CmdTypeCon, A>;
+
+        fn log(msg: String) -> Program<()> {
+            lift_f(Cmd::Log(msg, ()))
+        }
+
+        fn withdraw(balance: u32, amount: u32) -> Program<u32> {
+            if amount > balance {
+                lift_f(Cmd::Fail(format!("can't withdraw {}", amount)))
+            } else {
+                mdo! {
+                    ign &log(format!("withdrew {}", amount));
+                    ret<FreeTypeCon<CmdTypeCon>> balance - amount;
+                }
+            }
+        }
+
+        let workflow = |amounts: (u32, u32)| -> Program<String> {
+            mdo! {
+                ign &log(String::from("start"));
+                left =<< &withdraw(100, amounts.0);
+                left =<< &withdraw(*left, amounts.1);
+                ret<FreeTypeCon<CmdTypeCon>> format!("{} left", left);
+            }
+        };
+
+        // A test interpreter which records the log, and fails on the first failure
+        struct Recording(RefCell<Vec<String>>);
+
+        impl NaturalTransformation<CmdTypeCon, ResultTypeCon<String>> for Recording {
+            fn transform<T>(&self, x: Cmd<T>) -> Result<T, String> {
+                match x {
+                    Cmd::Log(msg, next) => {
+                        self.0.borrow_mut().push(msg);
+                        Ok(next)
+                    }
+                    Cmd::Fail(msg) => Err(msg),
+                }
+            }
+        }
+
+        let recording = Recording(RefCell::new(Vec::new()));
+        let result = fold_free(&recording, workflow((30, 50)));
+        assert_eq!(result, Ok(String::from("20 left")));
+        assert_eq!(
+            recording.0.into_inner(),
+            vec!["start", "withdrew 30", "withdrew 50"]
+        );
+
+        let recording = Recording(RefCell::new(Vec::new()));
+        let result = fold_free(&recording, workflow((30, 80)));
+        assert_eq!(result, Err(String::from("can't withdraw 80")));
+        assert_eq!(recording.0.into_inner(), vec!["start", "withdrew 30"]);
+
+        // The same program run by a different interpreter, which ignores the log
+        struct Quiet;
+
+        impl NaturalTransformation<CmdTypeCon, OptionTypeCon> for Quiet {
+            fn transform<T>(&self, x: Cmd<T>) -> Option<T> {
+                match x {
+                    Cmd::Log(_, next) => Some(next),
+                    Cmd::Fail(_) => None,
+                }
+            }
+        }
+
+        let program = fmap(|s: &String| s.len(), &workflow((1, 2)));
+        assert_eq!(fold_free(&Quiet, program), Some(7));
+        assert_eq!(fold_free(&Quiet, workflow((101, 0))), None);
+
+        // Programs can be chained by value too
+        let both = llift2_mut(
+            |x: String, y: String| x + ", " + &y,
+            workflow((1, 2)),
+            workflow((3, 4)),
+        );
+        assert_eq!(fold_free(&Quiet, both), Some(String::from("97 left, 93 left")));
+
+        // Instructions which ask the interpreter for a value hold a continuation taking it
+        enum Bank<Next> {
+            Balance(Rc<dyn Fn(u32) -> Next>),
+            Withdraw(u32, Next),
+        }
+
+        type_con!(struct BankTypeCon for<Next> Bank<Next>);
+
+        impl StaticFunctor for BankTypeCon {
+            fn map_static<TIn, TOut>(f: Rc<dyn Fn(TIn) -> TOut>, x: Bank<TIn>) -> Bank<TOut>
+            where
+                TIn: 'static,
+                TOut: 'static,
+            {
+                match x {
+                    Bank::Balance(k) => Bank::Balance(Rc::new(move |balance| f(k(balance)))),
+                    Bank::Withdraw(amount, next) => Bank::Withdraw(amount, f(next)),
+                }
+            }
+        }
+
+        // Reads the balance, then withdraws as much of `amount` as it can
+        let withdraw_up_to = |amount: u32| -> Free<BankTypeCon, u32> {
+            lift_f_static(Bank::Balance(Rc::new(|balance| balance))).bind(move |balance: u32| {
+                let taken = amount.min(balance);
+                lift_f_static(Bank::Withdraw(taken, ())).map(move |()| taken)
+            })
+        };
+
+        // A test interpreter with a fixed balance, which records the withdrawals
+        struct Fixed(u32, RefCell<Vec<u32>>);
+
+        impl NaturalTransformation<BankTypeCon, OptionTypeCon> for Fixed {
+            fn transform<T>(&self, x: Bank<T>) -> Option<T> {
+                match x {
+                    Bank::Balance(k) => Some(k(self.0)),
+                    Bank::Withdraw(amount, next) => {
+                        self.1.borrow_mut().push(amount);
+                        Some(next)
+                    }
+                }
+            }
+        }
+
+        let fixed = Fixed(30, RefCell::new(Vec::new()));
+        assert_eq!(fold_free(&fixed, withdraw_up_to(50)), Some(30));
+        assert_eq!(fold_free(&fixed, withdraw_up_to(10)), Some(10));
+        assert_eq!(fixed.1.into_inner(), vec![30, 10]);
+
+        // A production interpreter, where withdrawals change the balance
+        struct Ledger(RefCell<u32>);
+
+        impl NaturalTransformation<BankTypeCon, ResultTypeCon<String>> for Ledger {
+            fn transform<T>(&self, x: Bank<T>) -> Result<T, String> {
+                match x {
+                    Bank::Balance(k) => Ok(k(*self.0.borrow())),
+                    Bank::Withdraw(amount, next) => {
+                        let mut balance = self.0.borrow_mut();
+                        *balance = balance.checked_sub(amount).ok_or("overdrawn")?;
+                        Ok(next)
+                    }
+                }
+            }
+        }
+
+        let ledger = Ledger(RefCell::new(30));
+        let twice = withdraw_up_to(20)
+            .bind(move |first| withdraw_up_to(20).map(move |second| (first, second)));
+        assert_eq!(fold_free(&ledger, twice), Ok((20, 10)));
+        assert_eq!(ledger.0.into_inner(), 0);
+    }
+
+    #[test]
+    fn test_eff() {
+        use crate::eff::*;
+
+        // A user defined effect, which a program would otherwise get from a trait passed in
+        enum Console {
+            Print(String, Reply<()>),
+            Read(Reply<String>),
+        }
+
+        fn print<R: Member<Console, I>, I>(line: String) -> Eff<R, ()> {
+            send(|reply| Console::Print(line, reply))
+        }
+
+        fn read<R: Member<Console, I>, I>() -> Eff<R, String> {
+            send(Console::Read)
+        }
+
+        fn greet<R, I1, I2>() -> Eff<R, usize>
+        where
+            R: Member<Console, I1> + Member<State<usize>, I2> + 'static,
+        {
+            mdo! {
+                ign print(String::from("name?"));
+                ign modify(|n: &usize| n + 1);
+                count: usize =<< get();
+                let count = *count;
+                name: String =<< read();
+                ign print(format!("hello {} #{}", name, count));
+                ret<EffTypeCon<R>> count;
+            }
+        }
+
+        // A test handler, which reads canned input and writes the output to a Writer
+        fn canned<R, I, A>(x: Eff<(Console, R), A>, input: Vec<String>) -> Eff<R, A>
+        where
+            R: Member<Writer<Vec<String>>, I> + 'static,
+            A: 'static,
+        {
+            handle_state(
+                x,
+                input,
+                |_, y| Eff::pure(y),
+                |request, input, k| match request {
+                    Console::Print(line, reply) => {
+                        bind(tell(vec![line]), move |_| k.resume_with(input.clone(), reply, ()))
+                    }
+                    // Answering with anything but a `String` wouldn't compile
+                    Console::Read(reply) => {
+                        k.resume_with(input[1..].to_vec(), reply, input[0].clone())
+                    }
+                },
+            )
+        }
+
+        type Row = (Console, (State<usize>, (Writer<Vec<String>>, ())));
+        let program: Eff<Row, usize> = bind(greet::<Row, _, _>(), |_| greet());
+        let input = vec![String::from("Ann"), String::from("Bob")];
+        let ((count, state), output) = run(run_writer(run_state(canned(program, input), 10)));
+        assert_eq!((count, state), (12, 12));
+        assert_eq!(
+            output,
+            vec!["name?", "hello Ann #11", "name?", "hello Bob #12"]
+        );
+
+        // The order of the handlers decides whether the state survives an error
+        fn failing<R, I1, I2>() -> Eff<R, ()>
+        where
+            R: Member<State<u32>, I1> + Member<Error<String>, I2> + 'static,
+        {
+            mdo! {
+                ign put(1u32);
+                ign throw::<R, _, _, ()>(String::from("oops"));
+                ign put(2u32);
+                ret<EffTypeCon<R>> ();
+            }
+        }
+
+        type StateError = (State<u32>, (Error<String>, ()));
+        type ErrorState = (Error<String>, (State<u32>, ()));
+        assert_eq!(
+            run(run_error(run_state(failing::<StateError, _, _>(), 0))),
+            Err(String::from("oops"))
+        );
+        assert_eq!(
+            run(run_state(run_error(failing::<ErrorState, _, _>()), 0)),
+            (Err(String::from("oops")), 1)
+        );
+
+        // Reader
+        let program: Eff<(Reader<u32>, ()), u32> = fmap(|x: &u32| x * 2, ask());
+        assert_eq!(run(run_reader(program, 21)), 42);
+
+        // NonDet
+        fn triples<R: Member<NonDet, I> + 'static, I>(n: u32) -> Eff<R, (u32, u32, u32)> {
+            mdo! {
+                a: u32 =<< choose((1..=n).collect());
+                let a = *a;
+                b: u32 =<< choose((a..=n).collect());
+                let b = *b;
+                c: u32 =<< choose((b..=n).collect());
+                let c = *c;
+                ign guard(a * a + b * b == c * c);
+                ret<EffTypeCon<R>> (a, b, c);
+            }
+        }
+
+        assert_eq!(
+            run(run_non_det(triples::<(NonDet, ()), _>(13))),
+            vec![(3, 4, 5), (5, 12, 13), (6, 8, 10)]
+        );
+
+        // Long programs don't use up the stack
+        fn count_up<R: Member<State<u64>, I> + 'static, I: 'static>(n: u64) -> Eff<R, ()> {
+            if n == 0 {
+                Eff::pure(())
+            } else {
+                bind(modify(|x: &u64| x + 1), move |_| count_up::<R, I>(n - 1))
+            }
+        }
+
+        let program = count_up::<(State<u64>, ()), _>(100_000);
+        assert_eq!(run(run_state(program, 0)), ((), 100_000));
+
+        // Nor do binds nested to the left
+        let mut program: Eff<(State<u64>, ()), ()> = Eff::pure(());
+        for _ in 0..100_000 {
+            program = bind(program, |_| modify(|x: &u64| x + 1));
+        }
+        assert_eq!(run(run_state(program, 0)), ((), 100_000));
+    }
+
+    #[test]
+    fn test_free_ap() {
+        use std::collections::HashMap;
+        use std::rc::Rc;
+
+        // A setting read from the environment, with its default and a continuation taking its
+        // value
+        enum Setting<T> {
+            Number(String, u16, Rc<dyn Fn(u16) -> T>),
+            Text(String, String, Rc<dyn Fn(String) -> T>),
+        }
+
+        type_con!(struct SettingTypeCon for<T> Setting<T>);
+
+        impl StaticFunctor for SettingTypeCon {
+            fn map_static<TIn, TOut>(f: Rc<dyn Fn(TIn) -> TOut>, x: Setting<TIn>) -> Setting<TOut>
+            where
+                TIn: 'static,
+                TOut: 'static,
+            {
+                match x {
+                    Setting::Number(key, default, k) => {
+                        Setting::Number(key, default, Rc::new(move |n| f(k(n))))
+                    }
+                    Setting::Text(key, default, k) => {
+                        Setting::Text(key, default, Rc::new(move |s| f(k(s))))
+                    }
+                }
+            }
+        }
+
+        type Settings<A> = FreeAp<SettingTypeCon, A>;
+
+        fn number(key: &str, default: u16) -> Settings<u16> {
+            lift_ap_static(Setting::Number(String::from(key), default, Rc::new(|n| n)))
+        }
+
+        fn text(key: &str, default: &str) -> Settings<String> {
+            lift_ap_static(Setting::Text(String::from(key), String::from(default), Rc::new(|s| s)))
+        }
+
+        fn settings() -> Settings<(String, u16)> {
+            let address = FreeAp::lift2(
+                |host: &String, port: &u16| format!("{}:{}", host, port),
+                text("HOST", "localhost"),
+                number("PORT", 80),
+            );
+            FreeAp::lift2(
+                |address: &String, workers: &u16| (address.clone(), *workers),
+                address,
+                number("WORKERS", 1),
+            )
+        }
+
+        // All the keys, without reading any of them
+        let keys = run_ap_monoid(
+            |x: &Setting<Answer>| match x {
+                Setting::Number(key, ..) | Setting::Text(key, ..) => vec![key.clone()],
+            },
+            &settings(),
+        );
+        assert_eq!(keys, vec!["HOST", "PORT", "WORKERS"]);
+
+        // Each setting can only be answered through its continuation, with a value of its type
+        struct Env(HashMap<&'static str, &'static str>);
+
+        impl NaturalTransformation<SettingTypeCon, ResultTypeCon<String>> for Env {
+            fn transform<T>(&self, x: Setting<T>) -> Result<T, String> {
+                match x {
+                    Setting::Number(key, default, k) => match self.0.get(key.as_str()) {
+                        Some(value) => match value.parse::<u16>() {
+                            Ok(n) => Ok(k(n)),
+                            Err(_) => Err(format!("{} isn't a number", key)),
+                        },
+                        None => Ok(k(default)),
+                    },
+                    Setting::Text(key, default, k) => match self.0.get(key.as_str()) {
+                        Some(value) => Ok(k(String::from(*value))),
+                        None => Ok(k(default)),
+                    },
+                }
+            }
+        }
+
+        let env: HashMap<_, _> = vec![("PORT", "8080"), ("WORKERS", "4")]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            run_ap::<_, ResultTypeCon<String>, _, _>(&Env(env), settings()),
+            Ok((String::from("localhost:8080"), 4))
+        );
+
+        let env: HashMap<_, _> = vec![("PORT", "eighty")].into_iter().collect();
+        assert_eq!(
+            run_ap::<_, ResultTypeCon<String>, _, _>(&Env(env), settings()),
+            Err(String::from("PORT isn't a number"))
+        );
+
+        // Tries both 1 and 2 for every number
+        struct Both;
+
+        impl NaturalTransformation<SettingTypeCon, VecTypeCon> for Both {
+            fn transform<T>(&self, x: Setting<T>) -> Vec<T> {
+                match x {
+                    Setting::Number(_, _, k) => vec![k(1), k(2)],
+                    Setting::Text(_, default, k) => vec![k(default)],
+                }
+            }
+        }
+
+        let ports = number("PORT", 80).map(|port: &u16| port + 1);
+        assert_eq!(run_ap::<_, VecTypeCon, _, _>(&Both, ports), vec![2, 3]);
+        assert_eq!(
+            run_ap::<_, VecTypeCon, _, _>(&Both, settings()),
+            vec![
+                (String::from("localhost:1"), 1),
+                (String::from("localhost:1"), 2),
+                (String::from("localhost:2"), 1),
+                (String::from("localhost:2"), 2)
+            ]
+        );
+        assert_eq!(
+            run_ap_monoid(|_| vec![()], &FreeAp::<SettingTypeCon, _>::pure(1)).len(),
+            0
+        );
+
+        // Instructions which don't hold continuations only need to be LinearFunctors, like a Vec
+        // of choices
+        struct Choose;
+
+        impl NaturalTransformation<VecTypeCon, VecTypeCon> for Choose {
+            fn transform<T>(&self, x: Vec<T>) -> Vec<T> {
+                x
+            }
+        }
+
+        let sums = FreeAp::lift2(
+            |x: &u32, y: &u32| x + y,
+            lift_ap(vec![1, 2]),
+            lift_ap(vec![10, 20]),
+        );
+        assert_eq!(run_ap_monoid(|x: &Vec<Answer>| vec![x.len()], &sums), vec![2, 2]);
+        assert_eq!(run_ap::<_, VecTypeCon, _, _>(&Choose, sums), vec![11, 21, 12, 22]);
+
+        // Long programs don't use up the stack
+        let mut total = FreeAp::pure(0);
+        for _ in 0..100_000 {
+            total = FreeAp::lift2(|x: &u32, y: &u16| x + u32::from(*y), total, number("N", 1));
+        }
+        assert_eq!(
+            run_ap::<_, ResultTypeCon<String>, _, _>(&Env(HashMap::new()), total),
+            Ok(100_000)
+        );
+    }
+
+    // Compare these bounds with `map2` and `monadic_pair` below
+    fn gat_map2<TCon: gat::Functor, TIn, TMid, TOut>(
+        f: impl Fn(&TIn) -> TMid,
+        g: impl Fn(&TMid) -> TOut,
+        x: &TCon::Of<TIn>,
+    ) -> TCon::Of<TOut> {
+        TCon::fmap(g, &TCon::fmap(f, x))
+    }
+
+    fn gat_pair<TCon: gat::Monad, T: Clone>(
+        x: &TCon::Of<T>,
+        y: &TCon::Of<T>,
+    ) -> TCon::Of<(T, T)> {
+        TCon::bind(x, |x_val| TCon::fmap(|y_val| (x_val.clone(), y_val.clone()), y))
+    }
+
+    fn gat_sum<TCon: gat::Foldable>(x: &TCon::Of<u32>) -> u32 {
+        TCon::foldr(|y, acc| y + acc, 0, x)
+    }
+
     fn map2<TIn, TMid, TOut, TCon>(
         f: impl Fn(&TIn) -> TMid,
         g: impl Fn(&TMid) -> TOut,
@@ -127,6 +1929,7 @@ mod tests {
         map(g, map(f, x))
     }
 
+    #[allow(dead_code)]
     fn lmap2<TIn, TMid, TOut, TCon>(
         f: impl Fn(TIn) -> TMid,
         g: impl Fn(TMid) -> TOut,
@@ -122,4 +122,85 @@ macro_rules! ldo {
     ) => (
         lift::<$ty, _>($e)
     );
-}
\ No newline at end of file
+}
+
+// Like ldo!, but uses lbind_mut, so it also works for types like Vec which call
+// each continuation more than once.
+#[macro_export]
+macro_rules! ldo_mut {
+    (
+        let $p: pat = $e: expr ; $( $t: tt )*
+    ) => (
+        { let $p = $e ; ldo_mut! { $( $t )* } }
+    );
+
+    (
+        let $p: ident : $ty: ty = $e: expr ; $( $t: tt )*
+    ) => (
+        { let $p: $ty = $e ; ldo_mut! { $( $t )* } }
+    );
+
+    (
+        $p: pat =<< $e: expr ; $( $t: tt )*
+    ) => (
+        lbind_mut($e, move |$p| ldo_mut! { $( $t )* } )
+    );
+
+    (
+        $p: ident : $ty: ty =<< $e: expr ; $( $t: tt )*
+    ) => (
+        lbind_mut($e, move |$p : $ty| ldo_mut! { $( $t )* } )
+    );
+
+    (
+        ign $e: expr ; $( $t: tt )*
+    ) => (
+        lbind_mut($e, move |_| ldo_mut! { $( $t )* })
+    );
+
+    (
+        ret<$ty: ty> $e: expr ;
+    ) => (
+        lift::<$ty, _>($e)
+    );
+}
+
+// Like mdo!, but uses cbind and clift, for constrained monads like BTreeSet.
+#[macro_export]
+macro_rules! cdo {
+    (
+        let $p: pat = $e: expr ; $( $t: tt )*
+    ) => (
+        { let $p = $e ; cdo! { $( $t )* } }
+    );
+
+    (
+        let $p: ident : $ty: ty = $e: expr ; $( $t: tt )*
+    ) => (
+        { let $p: $ty = $e ; cdo! { $( $t )* } }
+    );
+
+    (
+        $p: pat =<< $e: expr ; $( $t: tt )*
+    ) => (
+        cbind($e, move |$p : &_| cdo! { $( $t )* } )
+    );
+
+    (
+        $p: ident : $ty: ty =<< $e: expr ; $( $t: tt )*
+    ) => (
+        cbind($e, move |$p : &$ty| cdo! { $( $t )* } )
+    );
+
+    (
+        ign $e: expr ; $( $t: tt )*
+    ) => (
+        cbind($e, move |_| cdo! { $( $t )* })
+    );
+
+    (
+        ret<$ty: ty> $e: expr ;
+    ) => (
+        clift::<$ty, _>($e)
+    );
+}
@@ -64,23 +64,92 @@ pub trait LinearMonad: LinearApplicative + Lift {
     }
 }
 
+// LinearMonadMut is to LinearMonad what LinearApplicativeMut is to LinearApplicative,
+// i.e. lbind_mut takes an FnMut so it can be called once for each value, which is what Vec needs.
+//
+// Every LinearMonad is also a LinearMonadMut.
+pub trait LinearMonadMut: LinearApplicativeMut + Lift {
+    fn lbind_mut<TIn, TOut, F>(
+        x: <Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: FnMut(TIn) -> <Self as WithTypeArg<TOut>>::Type;
+
+    fn ljoin_mut<T>(
+        x: <Self as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type,
+    ) -> <Self as WithTypeArg<T>>::Type
+    where
+        Self: WithTypeArg<T> + WithTypeArg<<Self as WithTypeArg<T>>::Type>,
+    {
+        <Self as LinearMonadMut>::lbind_mut::<<Self as WithTypeArg<T>>::Type, T, _>(x, |y| y)
+    }
+}
+
+impl<TCon> LinearMonadMut for TCon
+where
+    TCon: LinearMonad + ?Sized,
+{
+    fn lbind_mut<TIn, TOut, F>(
+        x: <TCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: FnMut(TIn) -> <TCon as WithTypeArg<TOut>>::Type,
+    {
+        <TCon as LinearMonad>::lbind::<TIn, TOut, F>(x, f)
+    }
+}
+
+// Monad for types which are only a SharedFunctor, like Rc and Arc.
+//
+// Every Monad is also a SharedMonad.
+pub trait SharedMonad: SharedApplicative {
+    fn bind_shared<TIn, TOut, F>(
+        x: &<Self as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <Self as WithTypeArg<TOut>>::Type
+    where
+        Self: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&TIn) -> <Self as WithTypeArg<TOut>>::Type;
+}
+
+impl<TCon> SharedMonad for TCon
+where
+    TCon: Monad + ?Sized,
+{
+    fn bind_shared<TIn, TOut, F>(
+        x: &<TCon as WithTypeArg<TIn>>::Type,
+        f: F,
+    ) -> <TCon as WithTypeArg<TOut>>::Type
+    where
+        TCon: WithTypeArg<TIn> + WithTypeArg<TOut>,
+        F: Fn(&TIn) -> <TCon as WithTypeArg<TOut>>::Type,
+    {
+        <TCon as Monad>::bind::<TIn, TOut, F>(x, f)
+    }
+}
+
 // bind(x, f)
 pub fn bind<TCon, TIn, TOut, TArg, F, TResult>(x: &TArg, f: F) -> TResult
 where
-    TCon: Monad + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    TCon: SharedMonad + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
     TArg: TypeApp<TCon, TIn> + ?Sized,
     F: Fn(&TIn) -> TResult,
     TResult: TypeApp<TCon, TOut>,
 {
-    Is::from_val(<TCon as Monad>::bind::<TIn, TOut, _>(x.into_ref(), |y| {
-        f(y).into_val()
-    }))
+    Is::from_val(<TCon as SharedMonad>::bind_shared::<TIn, TOut, _>(
+        x.into_ref(),
+        |y| f(y).into_val(),
+    ))
 }
 
 // bind_c(x, f)
 pub fn bind_c<TCon, TIn, TOut, F, TResult>(x: &<TCon as WithTypeArg<TIn>>::Type, f: F) -> TResult
 where
-    TCon: Monad + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    TCon: SharedMonad + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
     F: Fn(&TIn) -> TResult,
     TResult: TypeApp<TCon, TOut>,
 {
@@ -103,27 +172,17 @@ where
 
 // join(x)
 
-// "TInner: TypeApp<TCon, T>" implies "TInner = <TCon as WithTypeArg<TInner>>::Type",
-// indeed this is what `Is::into()` and associated functions do.
-//
-// But here we've got:
-//
-// "TInner: TypeApp<TCon, T>"
-//
-// and we want to convert:
+// "TInner: TypeApp<TCon, T>" implies "TInner = <TCon as WithTypeArg<T>>::Type",
+// but Rust won't let us convert:
 //
 // X<TInner>
 // to
-// X<<TCon as WithTypeArg<TInner>>::Type>"
+// X<<TCon as WithTypeArg<T>>::Type>
 //
-// Given the definition of `TypeApp` this should always be true (I believe it's impossible for
-// it not to be true) but I can't work out how  to write this safely, without using `fmap`.
+// directly, and doing `fmap` on the structure would take time,
+// whereas we want this to be a zero time operation.
 //
-// But doing `fmap` on the structure will take time,
-// whereas I want this to be a zero time operation.
-//
-// So we're naughty and use `transmute`. I believe this is still sound,
-// tell me loudly if you find a case where it isn't.
+// `TypeApp` requires the `TypeEq` witness though, which does exactly this conversion.
 fn into_functor_ref<TCon, T, TInner>(
     x: &<TCon as WithTypeArg<TInner>>::Type,
 ) -> &<TCon as WithTypeArg<<TCon as WithTypeArg<T>>::Type>>::Type
@@ -135,7 +194,7 @@ where
         + ?Sized,
     TInner: TypeApp<TCon, T>,
 {
-    unsafe { std::mem::transmute(x) }
+    <TInner as TypeEq<<TCon as WithTypeArg<T>>::Type>>::cast_ref::<TCon>(x)
 }
 
 pub fn fjoin<TCon, T, TInner, TArg>(x: &TArg) -> <TCon as WithTypeArg<T>>::Type
@@ -165,6 +224,20 @@ where
         |y| f(y).into_val(),
     ))
 }
+
+// lbind_mut(x, f)
+pub fn lbind_mut<TCon, TIn, TOut, X, F, TResult>(x: X, mut f: F) -> TResult
+where
+    TCon: LinearMonadMut + WithTypeArg<TIn> + WithTypeArg<TOut> + ?Sized,
+    X: TypeApp<TCon, TIn>,
+    F: FnMut(TIn) -> TResult,
+    TResult: TypeApp<TCon, TOut>,
+{
+    Is::from_val(<TCon as LinearMonadMut>::lbind_mut::<TIn, TOut, _>(
+        x.into_val(),
+        |y| f(y).into_val(),
+    ))
+}
 /*
 // lbind_c(x, f)
 pub fn lbind_c<TCon, TIn, TOut, F, X>(x: <TCon as WithTypeArg<TIn>>::Type, f: F) -> <TCon as WithTypeArg<TOut>>::Type
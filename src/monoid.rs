@@ -1,2 +1,6 @@
 use crate::*;
-pub trait Monoid : Semigroup + Default {}
\ No newline at end of file
+pub trait Monoid : Semigroup + Default {}
+
+impl<T> Monoid for Vec<T> {}
+
+impl Monoid for String {}
@@ -0,0 +1,209 @@
+use crate::*;
+
+// A Profunctor is contravariant in its first parameter and covariant in its second, like a
+// function. `dimap(f, g, x)` runs `f` on the way in and `g` on the way out.
+//
+// The instances hold boxed functions, so like `contramap`, everything passed to these is kept
+// inside the result, and it and the types involved have to be `'static`. Hence there are no
+// "by reference" versions either.
+pub trait Profunctor {
+    fn dimap<A, B, C, D, F, G>(
+        f: F,
+        g: G,
+        x: <Self as WithTypeArgs2<B, C>>::Type,
+    ) -> <Self as WithTypeArgs2<A, D>>::Type
+    where
+        Self: WithTypeArgs2<B, C> + WithTypeArgs2<A, D>,
+        F: Fn(A) -> B + 'static,
+        G: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static;
+
+    fn lmap_p<A, B, C, F>(
+        f: F,
+        x: <Self as WithTypeArgs2<B, C>>::Type,
+    ) -> <Self as WithTypeArgs2<A, C>>::Type
+    where
+        Self: WithTypeArgs2<B, C> + WithTypeArgs2<A, C>,
+        F: Fn(A) -> B + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        <Self as Profunctor>::dimap(f, |y: C| y, x)
+    }
+
+    fn rmap_p<A, B, C, G>(
+        g: G,
+        x: <Self as WithTypeArgs2<A, B>>::Type,
+    ) -> <Self as WithTypeArgs2<A, C>>::Type
+    where
+        Self: WithTypeArgs2<A, B> + WithTypeArgs2<A, C>,
+        G: Fn(B) -> C + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        <Self as Profunctor>::dimap(|y: A| y, g, x)
+    }
+}
+
+// dimap(f, g, x)
+pub fn dimap<TCon, A, B, C, D, F, G, X>(f: F, g: G, x: X) -> <TCon as WithTypeArgs2<A, D>>::Type
+where
+    TCon: Profunctor + WithTypeArgs2<B, C> + WithTypeArgs2<A, D> + ?Sized,
+    F: Fn(A) -> B + 'static,
+    G: Fn(C) -> D + 'static,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    D: 'static,
+    X: TypeApp2<TCon, B, C>,
+{
+    <TCon as Profunctor>::dimap(f, g, x.into_val())
+}
+
+// lmap_p(f, x)
+pub fn lmap_p<TCon, A, B, C, F, X>(f: F, x: X) -> <TCon as WithTypeArgs2<A, C>>::Type
+where
+    TCon: Profunctor + WithTypeArgs2<B, C> + WithTypeArgs2<A, C> + ?Sized,
+    F: Fn(A) -> B + 'static,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    X: TypeApp2<TCon, B, C>,
+{
+    <TCon as Profunctor>::lmap_p(f, x.into_val())
+}
+
+// rmap_p(g, x)
+pub fn rmap_p<TCon, A, B, C, G, X>(g: G, x: X) -> <TCon as WithTypeArgs2<A, C>>::Type
+where
+    TCon: Profunctor + WithTypeArgs2<A, B> + WithTypeArgs2<A, C> + ?Sized,
+    G: Fn(B) -> C + 'static,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    X: TypeApp2<TCon, A, B>,
+{
+    <TCon as Profunctor>::rmap_p(g, x.into_val())
+}
+
+// Strong profunctors can pass extra values through alongside the ones they act on.
+//
+// The extra value has to be `Clone`, as an instance like `KleisliTypeCon<VecTypeCon>` needs a
+// copy of it for each result.
+pub trait Strong: Profunctor {
+    fn first<A, B, C>(
+        x: <Self as WithTypeArgs2<A, B>>::Type,
+    ) -> <Self as WithTypeArgs2<(A, C), (B, C)>>::Type
+    where
+        Self: WithTypeArgs2<A, B> + WithTypeArgs2<(A, C), (B, C)>,
+        A: 'static,
+        B: 'static,
+        C: Clone + 'static;
+
+    fn second<A, B, C>(
+        x: <Self as WithTypeArgs2<A, B>>::Type,
+    ) -> <Self as WithTypeArgs2<(C, A), (C, B)>>::Type
+    where
+        Self: WithTypeArgs2<A, B> + WithTypeArgs2<(A, C), (B, C)> + WithTypeArgs2<(C, A), (C, B)>,
+        A: 'static,
+        B: 'static,
+        C: Clone + 'static,
+    {
+        <Self as Profunctor>::dimap(
+            |(c, a): (C, A)| (a, c),
+            |(b, c): (B, C)| (c, b),
+            <Self as Strong>::first::<A, B, C>(x),
+        )
+    }
+}
+
+// first(x)
+pub fn first<TCon, A, B, C, X>(x: X) -> <TCon as WithTypeArgs2<(A, C), (B, C)>>::Type
+where
+    TCon: Strong + WithTypeArgs2<A, B> + WithTypeArgs2<(A, C), (B, C)> + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: Clone + 'static,
+    X: TypeApp2<TCon, A, B>,
+{
+    <TCon as Strong>::first(x.into_val())
+}
+
+// second(x)
+pub fn second<TCon, A, B, C, X>(x: X) -> <TCon as WithTypeArgs2<(C, A), (C, B)>>::Type
+where
+    TCon: Strong
+        + WithTypeArgs2<A, B>
+        + WithTypeArgs2<(A, C), (B, C)>
+        + WithTypeArgs2<(C, A), (C, B)>
+        + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: Clone + 'static,
+    X: TypeApp2<TCon, A, B>,
+{
+    <TCon as Strong>::second(x.into_val())
+}
+
+// Choice profunctors can act on one alternative of an Either, passing the other through.
+pub trait Choice: Profunctor {
+    fn left<A, B, C>(
+        x: <Self as WithTypeArgs2<A, B>>::Type,
+    ) -> <Self as WithTypeArgs2<Either<A, C>, Either<B, C>>>::Type
+    where
+        Self: WithTypeArgs2<A, B> + WithTypeArgs2<Either<A, C>, Either<B, C>>,
+        A: 'static,
+        B: 'static,
+        C: 'static;
+
+    fn right<A, B, C>(
+        x: <Self as WithTypeArgs2<A, B>>::Type,
+    ) -> <Self as WithTypeArgs2<Either<C, A>, Either<C, B>>>::Type
+    where
+        Self: WithTypeArgs2<A, B>
+            + WithTypeArgs2<Either<A, C>, Either<B, C>>
+            + WithTypeArgs2<Either<C, A>, Either<C, B>>,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+    {
+        <Self as Profunctor>::dimap(
+            Either::<C, A>::mirror,
+            Either::<B, C>::mirror,
+            <Self as Choice>::left::<A, B, C>(x),
+        )
+    }
+}
+
+// left(x)
+pub fn left<TCon, A, B, C, X>(x: X) -> <TCon as WithTypeArgs2<Either<A, C>, Either<B, C>>>::Type
+where
+    TCon: Choice + WithTypeArgs2<A, B> + WithTypeArgs2<Either<A, C>, Either<B, C>> + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    X: TypeApp2<TCon, A, B>,
+{
+    <TCon as Choice>::left(x.into_val())
+}
+
+// right(x)
+pub fn right<TCon, A, B, C, X>(x: X) -> <TCon as WithTypeArgs2<Either<C, A>, Either<C, B>>>::Type
+where
+    TCon: Choice
+        + WithTypeArgs2<A, B>
+        + WithTypeArgs2<Either<A, C>, Either<B, C>>
+        + WithTypeArgs2<Either<C, A>, Either<C, B>>
+        + ?Sized,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    X: TypeApp2<TCon, A, B>,
+{
+    <TCon as Choice>::right(x.into_val())
+}
@@ -1,3 +1,17 @@
 pub trait Semigroup where {
-    fn semigroup_op(self : Self, other : Self) -> Self;
-}
\ No newline at end of file
+    fn semigroup_op(self, other : Self) -> Self;
+}
+
+impl<T> Semigroup for Vec<T> {
+    fn semigroup_op(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+impl Semigroup for String {
+    fn semigroup_op(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
@@ -1,12 +1,14 @@
 use crate::*;
 
+// The applicative is `gat::HigherKinded`, so it can be applied to any type, not just `T` and the
+// final structure. Without that there's no way to hold the partial results of a structure with
+// more than two elements while it's being rebuilt.
 pub trait LinearTraversable: LinearFunctor + LinearFoldable {
     fn sequence<TApplicative, T>(
         x: <Self as WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>>::Type,
     ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<T>>::Type>>::Type
     where
-        TApplicative:
-            LinearApplicative + WithTypeArg<T> + WithTypeArg<<Self as WithTypeArg<T>>::Type>,
+        TApplicative: LinearApplicative + gat::HigherKinded,
         Self: WithTypeArg<T> + WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>;
 
     fn traverse<TApplicative, TIn, TOut, F>(
@@ -15,8 +17,7 @@ pub trait LinearTraversable: LinearFunctor + LinearFoldable {
     ) -> <TApplicative as WithTypeArg<<Self as WithTypeArg<TOut>>::Type>>::Type
     where
         F: Fn(TIn) -> <TApplicative as WithTypeArg<TOut>>::Type,
-        TApplicative:
-            LinearApplicative + WithTypeArg<<Self as WithTypeArg<TOut>>::Type> + WithTypeArg<TOut>,
+        TApplicative: LinearApplicative + gat::HigherKinded,
         Self: WithTypeArg<TIn>
             + WithTypeArg<TOut>
             + WithTypeArg<<TApplicative as typeapp::WithTypeArg<TOut>>::Type>,
@@ -25,6 +26,28 @@ pub trait LinearTraversable: LinearFunctor + LinearFoldable {
     }
 }
 
+// Combines the effects in `x` in order, collecting their results into a `C`. This is `sequence`
+// for the collections which can be built up an element at a time.
+pub(crate) fn sequence_iter<TApplicative, T, C>(
+    x: impl IntoIterator<Item = <TApplicative as WithTypeArg<T>>::Type>,
+) -> <TApplicative as WithTypeArg<C>>::Type
+where
+    TApplicative: LinearApplicative + gat::HigherKinded,
+    C: Default + Extend<T>,
+{
+    x.into_iter()
+        .fold(<TApplicative as Lift>::lift(C::default()), |acc, y| {
+            <TApplicative as LinearApplicative>::llift2(
+                |mut ys: C, y: T| {
+                    ys.extend(Some(y));
+                    ys
+                },
+                acc,
+                y,
+            )
+        })
+}
+
 pub fn traverse<TCon, TApplicative, TIn, TOut, F, X>(
     f: F,
     x: X,
@@ -32,8 +55,7 @@ pub fn traverse<TCon, TApplicative, TIn, TOut, F, X>(
 where
     F: Fn(TIn) -> <TApplicative as WithTypeArg<TOut>>::Type,
     X: TypeApp<TCon, TIn>,
-    TApplicative:
-        LinearApplicative + WithTypeArg<TOut> + WithTypeArg<<TCon as WithTypeArg<TOut>>::Type>,
+    TApplicative: LinearApplicative + gat::HigherKinded,
     TCon: LinearTraversable
         + WithTypeArg<TIn>
         + WithTypeArg<TOut>
@@ -47,7 +69,7 @@ pub fn sequence<TCon, TApplicative, T, X, Y>(
     x: X,
 ) -> <TApplicative as WithTypeArg<<TCon as WithTypeArg<T>>::Type>>::Type
 where
-    TApplicative: LinearApplicative + WithTypeArg<T> + WithTypeArg<<TCon as WithTypeArg<T>>::Type>,
+    TApplicative: LinearApplicative + gat::HigherKinded,
     TCon: LinearTraversable
         + WithTypeArg<T>
         + WithTypeArg<<TApplicative as WithTypeArg<T>>::Type>
@@ -0,0 +1,81 @@
+// Generates the type constructor boilerplate for a type, that is, the marker struct
+// and the `TypeAppParam`, `TypeApp` and `HigherKinded` impls (which gives `WithTypeArg`).
+//
+// The `for<T>` part names the parameter that the type constructor abstracts over:
+//
+// type_con!(pub struct TreeTypeCon for<T> Tree<T>);
+//
+// Any other parameters are fixed, and become parameters of the type constructor itself:
+//
+// type_con!(pub struct ResultTypeCon<E> for<T> Result<T, E>);
+//
+// With two parameters in the `for<A, B>` part it generates a two parameter type constructor
+// instead, implementing `WithTypeArgs2` and `TypeApp2`:
+//
+// type_con!(pub struct ForgetTypeCon<R> for<A, B> Forget<R, A, B>);
+#[macro_export]
+macro_rules! type_con {
+    (
+        $(#[$attr: meta])* $vis: vis struct $con: ident for<$t: ident> $target: ty $(;)?
+    ) => (
+        $(#[$attr])*
+        $vis struct $con;
+
+        impl<$t> $crate::TypeAppParam for $target {
+            type Param = $t;
+        }
+
+        impl<$t> $crate::TypeApp<$con, $t> for $target {}
+
+        impl $crate::gat::HigherKinded for $con {
+            type Of<$t> = $target;
+        }
+    );
+
+    (
+        $(#[$attr: meta])* $vis: vis struct $con: ident<$( $param: ident ),+> for<$t: ident> $target: ty $(;)?
+    ) => (
+        $(#[$attr])*
+        $vis struct $con<$( $param ),+> {
+            _unused: std::marker::PhantomData<($( $param, )+)>,
+        }
+
+        impl<$t, $( $param ),+> $crate::TypeAppParam for $target {
+            type Param = $t;
+        }
+
+        impl<$t, $( $param ),+> $crate::TypeApp<$con<$( $param ),+>, $t> for $target {}
+
+        impl<$( $param ),+> $crate::gat::HigherKinded for $con<$( $param ),+> {
+            type Of<$t> = $target;
+        }
+    );
+
+    (
+        $(#[$attr: meta])* $vis: vis struct $con: ident for<$a: ident, $b: ident> $target: ty $(;)?
+    ) => (
+        $(#[$attr])*
+        $vis struct $con;
+
+        impl<$a, $b> $crate::TypeApp2<$con, $a, $b> for $target {}
+
+        impl<$a, $b> $crate::WithTypeArgs2<$a, $b> for $con {
+            type Type = $target;
+        }
+    );
+
+    (
+        $(#[$attr: meta])* $vis: vis struct $con: ident<$( $param: ident ),+> for<$a: ident, $b: ident> $target: ty $(;)?
+    ) => (
+        $(#[$attr])*
+        $vis struct $con<$( $param ),+> {
+            _unused: std::marker::PhantomData<($( $param, )+)>,
+        }
+
+        impl<$a, $b, $( $param ),+> $crate::TypeApp2<$con<$( $param ),+>, $a, $b> for $target {}
+
+        impl<$a, $b, $( $param ),+> $crate::WithTypeArgs2<$a, $b> for $con<$( $param ),+> {
+            type Type = $target;
+        }
+    );
+}
@@ -7,13 +7,48 @@ pub trait WithTypeArg<T: ?Sized> {
 }
 
 pub trait TypeApp<TCon, T>:
-    is_type::Is<Type = <TCon as WithTypeArg<T>>::Type> + TypeAppParam
+    is_type::Is<Type = <TCon as WithTypeArg<T>>::Type>
+    + TypeEq<<TCon as WithTypeArg<T>>::Type>
+    + TypeAppParam
 where
     TCon: WithTypeArg<T> + ?Sized,
     T: ?Sized,
 {
 }
 
+// `Is` lets us convert between `X` and `Y` when `X: Is<Type = Y>`, but it can't do so
+// underneath a type constructor, i.e. it can't turn a `Vec<X>` into a `Vec<Y>`.
+//
+// `TypeEq` is a type equality witness which can. Its only implementation is the reflexive one,
+// where `cast` is just the identity function, so we get the conversion without any unsafe code.
+//
+// `TypeApp` requires this, so anywhere you've got `X: TypeApp<TCon, T>` you can use it.
+pub trait TypeEq<U: ?Sized> {
+    fn cast<TCon>(x: <TCon as WithTypeArg<Self>>::Type) -> <TCon as WithTypeArg<U>>::Type
+    where
+        TCon: WithTypeArg<Self> + WithTypeArg<U> + ?Sized;
+
+    fn cast_ref<TCon>(x: &<TCon as WithTypeArg<Self>>::Type) -> &<TCon as WithTypeArg<U>>::Type
+    where
+        TCon: WithTypeArg<Self> + WithTypeArg<U> + ?Sized;
+}
+
+impl<T: ?Sized> TypeEq<T> for T {
+    fn cast<TCon>(x: <TCon as WithTypeArg<T>>::Type) -> <TCon as WithTypeArg<T>>::Type
+    where
+        TCon: WithTypeArg<T> + ?Sized,
+    {
+        x
+    }
+
+    fn cast_ref<TCon>(x: &<TCon as WithTypeArg<T>>::Type) -> &<TCon as WithTypeArg<T>>::Type
+    where
+        TCon: WithTypeArg<T> + ?Sized,
+    {
+        x
+    }
+}
+
 pub struct Ref {}
 pub struct Val {}
 
@@ -43,3 +78,18 @@ where
     T: ?Sized,
 {
 }
+
+// The same encoding for type constructors with two parameters, like functions, which profunctors
+// are written against. There's no equivalent of `TypeEq`, as nothing needs to convert values
+// underneath one of these yet.
+pub trait WithTypeArgs2<A: ?Sized, B: ?Sized> {
+    type Type: TypeApp2<Self, A, B>;
+}
+
+pub trait TypeApp2<TCon, A, B>: is_type::Is<Type = <TCon as WithTypeArgs2<A, B>>::Type>
+where
+    TCon: WithTypeArgs2<A, B> + ?Sized,
+    A: ?Sized,
+    B: ?Sized,
+{
+}